@@ -1,24 +1,131 @@
-use crate::values::{ValidRandomNumber, ValidRandomRange};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::values::{ValidRandomNumber, ValidRandomRange, ValidRandomSeed, ValidRandomState};
+
+// Amount of seeds taken from the time so far, shared by the whole process.
+static TIME_SEEDS: AtomicU64 = AtomicU64::new(0);
+
+/// Returns a seed taken from the current time in nanoseconds, mixed with a global counter so
+/// that calls within the same clock tick don't return the same seed.
+pub(crate) fn time_seed() -> u64 {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_nanos() as u64,
+        Err(_) => 1
+    };
+
+    // spread the counter over every bit so it doesn't only change the low ones
+    let count = TIME_SEEDS.fetch_add(1, Ordering::Relaxed).wrapping_mul(0x9E3779B97F4A7C15);
+
+    return nanos ^ count;
+}
 
 /// The `RandomAlgorithm` trait.
 /// 
 /// This trait defines what a struct needs to implement to be considered a valid random algorithm.
 /// 
-/// It defines a `Seed` type and a `Number` type, the latter needing to implement the
-/// `ValidRandomNumber` trait.
+/// It defines a `Seed` type and a `Number` type, which need to implement the `ValidRandomSeed` and
+/// `ValidRandomNumber` traits respectively.
 pub trait RandomAlgorithm {
-    type Seed;
+    type Seed: ValidRandomSeed;
     type Number: ValidRandomNumber;
 
+    /// Name of the algorithm, `"Unknown"` unless the algorithm sets it.
+    const NAME: &'static str = "Unknown";
+
+    /// Base 2 logarithm of the period of the algorithm, rounded to the nearest integer, e.g.
+    /// 19937 for a period of `2^19937 - 1`. Zero means that the period is unknown.
+    const PERIOD_LOG2: u32 = 0;
+
     /// Creates a new algorithm with the given seed value.
     /// 
     /// Returns a `Result` due to the fact that some implementations may fail due to the use of
     /// invalid seeds.
     fn new(seed: Self::Seed) -> Result<Self, &'static str> where Self: Sized;
 
+    /// Checks if a seed is valid for the algorithm, without creating it.
+    /// 
+    /// Returns the same error that `new` would return for the seed. By default every seed is
+    /// valid, algorithms with invalid seeds need to override it and call it from `new`.
+    fn validate_seed(_seed: &Self::Seed) -> Result<(), &'static str> {
+        return Ok(());
+    }
+
+    /// Creates a new algorithm from a `u64` value, whatever the type of its seed is.
+    /// 
+    /// By default the value is mixed into a seed with `ValidRandomSeed::from_u64`, so values that
+    /// only differ in bits the seed can't hold still give different seeds.
+    fn from_u64(seed: u64) -> Result<Self, &'static str> where Self: Sized {
+        return Self::new(Self::Seed::from_u64(seed));
+    }
+
     /// Creates and returns a default implementation, generally with the time as a seed.
+    /// 
+    /// The algorithms of this crate use `from_u64` with the time in nanoseconds combined with a
+    /// counter, so consecutive calls give different generators.
     fn default() -> Self;
 
+    /// Returns the next number generated by the algorithm, without fitting it to any range.
+    fn next_raw(&mut self) -> Self::Number;
+
+    /// Returns how many of the lowest bits of a raw number are random.
+    /// 
+    /// Defaults to all the bits of `Number`, algorithms that generate fewer bits than that need to
+    /// override it.
+    fn raw_bits() -> u32 {
+        return Self::Number::bits();
+    }
+
     /// Returns a random number in the given range.
-    fn randrange<R: ValidRandomRange<Self::Number>>(&mut self, range: R) -> Self::Number;
+    /// 
+    /// A range whose end is smaller than its start is treated as if its bounds were swapped, e.g.
+    /// `10..3` behaves like `3..10`.
+    /// 
+    /// Panics if the range is empty.
+    fn randrange<R: ValidRandomRange<Self::Number>>(&mut self, range: R) -> Self::Number {
+        let mut start = range._start();
+        let mut end = range._end();
+
+        if end < start {
+            std::mem::swap(&mut start, &mut end);
+        }
+
+        let mut width = end.wrapping_sub(start);
+
+        if range._inclusive() {
+            // wraps to zero when the range covers every number
+            width = width.wrapping_add(Self::Number::one());
+        } else if width == Self::Number::zero() {
+            panic!("can't get a random number from an empty range");
+        }
+
+        let value = self.next_raw();
+
+        if width == Self::Number::zero() {
+            return value.wrapping_add(start);
+        }
+
+        return value.unsigned_rem(width).wrapping_add(start);
+    }
+}
+
+/// The `StatefulAlgorithm` trait.
+/// 
+/// This trait defines how to copy and restore the full internal state of a random algorithm, so
+/// that a sequence can be continued exactly where it was left.
+pub trait StatefulAlgorithm: RandomAlgorithm {
+    type State: ValidRandomState;
+
+    /// Returns a copy of the internal state of the algorithm.
+    fn state(&self) -> Self::State;
+
+    /// Creates an algorithm from the given internal state, as returned by `state`.
+    fn from_state(state: Self::State) -> Self;
+
+    /// Checks if an internal state is valid for the algorithm, e.g. one that can't leave zero.
+    /// 
+    /// By default every state is valid, algorithms with degenerate states need to override it.
+    fn validate_state(_state: &Self::State) -> Result<(), &'static str> {
+        return Ok(());
+    }
 }
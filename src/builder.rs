@@ -0,0 +1,117 @@
+use crate::algorithm::RandomAlgorithm;
+use crate::rand::Random;
+
+/// Struct `RandomBuilder`, used to configure and create a `Random` struct.
+/// 
+/// e.g.
+/// ```rust
+/// # use rnglib::{RandomBuilder, Random, MersenneTwister};
+/// let rng: Random<MersenneTwister> = RandomBuilder::new()
+///     .seed(42)
+///     .gaussian_cache(false)
+///     .build()
+///     .unwrap();
+/// ```
+pub struct RandomBuilder<T>
+    where T: RandomAlgorithm
+{
+    seed: Option<T::Seed>,
+    gaussian_cache: bool,
+    audited: bool
+}
+
+impl<T> RandomBuilder<T>
+    where T: RandomAlgorithm
+{
+    /// Creates a new `RandomBuilder`, which by default builds a generator with a default seed and
+    /// the gaussian cache enabled, without keeping a digest.
+    pub fn new() -> RandomBuilder<T> {
+        return RandomBuilder { seed: None, gaussian_cache: true, audited: false };
+    }
+
+    /// Sets the seed for the underlying algorithm.
+    pub fn seed(mut self, seed: T::Seed) -> RandomBuilder<T> {
+        self.seed = Some(seed);
+        return self;
+    }
+
+    /// Uses a default seed for the underlying algorithm, like `Random::new`, undoing any previous
+    /// call to `seed`.
+    pub fn from_entropy(mut self) -> RandomBuilder<T> {
+        self.seed = None;
+        return self;
+    }
+
+    /// Sets whether `gaussian` keeps the second number of each pair for the next call. Disabling
+    /// it makes every call draw a new pair.
+    pub fn gaussian_cache(mut self, enabled: bool) -> RandomBuilder<T> {
+        self.gaussian_cache = enabled;
+        return self;
+    }
+
+    /// Sets whether the generator keeps a digest of every value it generates, returned by
+    /// `Random::digest`.
+    pub fn audited(mut self, enabled: bool) -> RandomBuilder<T> {
+        self.audited = enabled;
+        return self;
+    }
+
+    /// Returns a `Result` containing the configured `Random` struct, or the error of the algorithm
+    /// if the seed is invalid.
+    pub fn build(self) -> Result<Random<T>, &'static str> {
+        let mut rng = match self.seed {
+            Some(seed) => Random::seed(seed)?,
+            None => Random::new()
+        };
+        rng.set_gaussian_cache(self.gaussian_cache);
+        rng.set_audited(self.audited);
+
+        return Ok(rng);
+    }
+}
+
+impl<T> Default for RandomBuilder<T>
+    where T: RandomAlgorithm
+{
+    fn default() -> RandomBuilder<T> {
+        return RandomBuilder::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mersennetwister::MersenneTwister;
+    use crate::xorshift::XORShift32;
+
+    #[test]
+    fn build_seeded() {
+        let mut rng: Random<MersenneTwister> = RandomBuilder::new().seed(10).build().unwrap();
+        let mut expected: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert!(rng == expected);
+        assert_eq!(rng.randrange(0..10), expected.randrange(0..10));
+        assert_eq!(rng.seed_used(), Some(10));
+    }
+
+    #[test]
+    fn build_from_entropy() {
+        let rng: Random<MersenneTwister> = RandomBuilder::new().seed(10).from_entropy().build()
+            .unwrap();
+        assert_eq!(rng.seed_used(), None);
+        assert!(RandomBuilder::<XORShift32>::new().seed(0).build().is_err());
+    }
+
+    #[test]
+    fn build_without_gaussian_cache() {
+        let mut rng: Random<MersenneTwister> = RandomBuilder::new()
+            .seed(10)
+            .gaussian_cache(false)
+            .build()
+            .unwrap();
+        let mut expected: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.gaussian(0.0, 1.0), expected.gaussian(0.0, 1.0));
+        // the cached generator uses its spare number, the other one draws a new pair
+        expected.gaussian(0.0, 1.0);
+        assert_eq!(rng.gaussian(0.0, 1.0), expected.gaussian(0.0, 1.0));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::algorithm::{RandomAlgorithm, time_seed};
+
+// Implementation for the ChaCha stream cipher with 8 rounds, used as a generator
+// https://cr.yp.to/chacha/chacha-20080128.pdf
+/// ChaCha8 algorithm.
+/// 
+/// Its numbers have a much higher statistical quality than the other algorithms, but it's still
+/// not meant for cryptographic needs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChaCha8 {
+    key: [u32; 8],
+    counter: u64,
+    block: [u32; 16], // output of the last block
+    index: usize
+}
+
+impl ChaCha8 {
+    /// The quarter round operation, part of the algorithm.
+    fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(16);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(12);
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(8);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(7);
+    }
+
+    /// Generates the next block of 16 numbers and advances the block counter.
+    fn refill(&mut self) {
+        // "expand 32-byte k", then the key, the block counter and a nonce of zero
+        let mut input = [0; 16];
+        input[..4].copy_from_slice(&[0x61707865, 0x3320646E, 0x79622D32, 0x6B206574]);
+        input[4..12].copy_from_slice(&self.key);
+        input[12] = self.counter as u32;
+        input[13] = (self.counter >> 32) as u32;
+
+        let mut state = input;
+
+        // each iteration is a column round and a diagonal round
+        for _ in 0..4 {
+            ChaCha8::quarter_round(&mut state, 0, 4, 8, 12);
+            ChaCha8::quarter_round(&mut state, 1, 5, 9, 13);
+            ChaCha8::quarter_round(&mut state, 2, 6, 10, 14);
+            ChaCha8::quarter_round(&mut state, 3, 7, 11, 15);
+            ChaCha8::quarter_round(&mut state, 0, 5, 10, 15);
+            ChaCha8::quarter_round(&mut state, 1, 6, 11, 12);
+            ChaCha8::quarter_round(&mut state, 2, 7, 8, 13);
+            ChaCha8::quarter_round(&mut state, 3, 4, 9, 14);
+        }
+
+        for i in 0..16 {
+            self.block[i] = state[i].wrapping_add(input[i]);
+        }
+
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+impl RandomAlgorithm for ChaCha8 {
+    type Seed = [u8; 32];
+    type Number = u32;
+    const NAME: &'static str = "ChaCha8";
+    // 2^64 blocks of 16 numbers
+    const PERIOD_LOG2: u32 = 68;
+
+    fn new(seed: Self::Seed) -> Result<ChaCha8, &'static str> {
+        let mut key = [0; 8];
+
+        for (word, bytes) in key.iter_mut().zip(seed.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+
+        // the first call to next_raw generates the first block
+        return Ok(ChaCha8 { key, counter: 0, block: [0; 16], index: 16 });
+    }
+
+    fn default() -> ChaCha8 {
+        return ChaCha8::from_u64(time_seed()).unwrap();
+    }
+
+    fn next_raw(&mut self) -> Self::Number {
+        if self.index >= 16 {
+            self.refill();
+        }
+
+        let value = self.block[self.index];
+        self.index += 1;
+
+        return value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chacha8_test_vector() {
+        // https://datatracker.ietf.org/doc/html/draft-strombergson-chacha-test-vectors-00
+        // TC1: all zero key and IV, 8 rounds
+        let mut random = ChaCha8::new([0; 32]).unwrap();
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for _ in 0..16 {
+            bytes.extend_from_slice(&random.next_raw().to_le_bytes());
+        }
+
+        assert_eq!(bytes, [
+            0x3E, 0x00, 0xEF, 0x2F, 0x89, 0x5F, 0x40, 0xD6, 0x7F, 0x5B, 0xB8, 0xE8, 0x1F, 0x09,
+            0xA5, 0xA1, 0x2C, 0x84, 0x0E, 0xC3, 0xCE, 0x9A, 0x7F, 0x3B, 0x18, 0x1B, 0xE1, 0x88,
+            0xEF, 0x71, 0x1A, 0x1E, 0x98, 0x4C, 0xE1, 0x72, 0xB9, 0x21, 0x6F, 0x41, 0x9F, 0x44,
+            0x53, 0x67, 0x45, 0x6D, 0x56, 0x19, 0x31, 0x4A, 0x42, 0xA3, 0xDA, 0x86, 0xB0, 0x01,
+            0x38, 0x7B, 0xFD, 0xB8, 0x0E, 0x0C, 0xFE, 0x42
+        ]);
+    }
+
+    #[test]
+    fn chacha8_random_value() {
+        let mut random = ChaCha8::from_u64(10).unwrap();
+        let value = random.randrange(1..5);
+        assert!((1..5).contains(&value));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::algorithm::RandomAlgorithm;
+
+/// Algorithm combining the numbers of two generators of the same algorithm with a XOR.
+/// 
+/// Its seed is a pair with the seeds of both generators.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Combined<T>
+    where T: RandomAlgorithm
+{
+    first: T,
+    second: T
+}
+
+impl<T> Combined<T>
+    where T: RandomAlgorithm
+{
+    /// Creates a `Combined` algorithm from two existing generators.
+    pub(crate) fn pair(first: T, second: T) -> Combined<T> {
+        return Combined { first, second };
+    }
+}
+
+impl<T> RandomAlgorithm for Combined<T>
+    where T: RandomAlgorithm
+{
+    type Seed = (T::Seed, T::Seed);
+    type Number = T::Number;
+    const NAME: &'static str = "Combined";
+    // only the period of a single generator is guaranteed
+    const PERIOD_LOG2: u32 = T::PERIOD_LOG2;
+
+    fn new(seed: Self::Seed) -> Result<Combined<T>, &'static str> {
+        return Ok(Combined { first: T::new(seed.0)?, second: T::new(seed.1)? });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
+        T::validate_seed(&seed.0)?;
+        return T::validate_seed(&seed.1);
+    }
+
+    fn default() -> Combined<T> {
+        // default seeds never repeat, so both generators are different
+        return Combined { first: T::default(), second: T::default() };
+    }
+
+    fn next_raw(&mut self) -> Self::Number {
+        return self.first.next_raw() ^ self.second.next_raw();
+    }
+
+    fn raw_bits() -> u32 {
+        return T::raw_bits();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mersennetwister::MersenneTwister;
+
+    #[test]
+    fn combined_random_value() {
+        let mut random: Combined<MersenneTwister> = Combined::new((10, 20)).unwrap();
+        let mut first = MersenneTwister::new(10).unwrap();
+        let mut second = MersenneTwister::new(20).unwrap();
+
+        for _ in 0..100 {
+            assert_eq!(random.next_raw(), first.next_raw() ^ second.next_raw());
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::cell::RefCell;
+
+use crate::mersennetwister::MersenneTwister;
+use crate::rand::Random;
+use crate::values::{ValidRandomRange, Shuffleable};
+
+thread_local! {
+    // Generator used by the free functions, one per thread.
+    static GLOBAL: RefCell<Random<MersenneTwister>> = RefCell::new(Random::new());
+}
+
+/// Seeds the global generator of the current thread, making the values returned by the free
+/// functions reproducible.
+/// 
+/// e.g.
+/// ```rust
+/// rnglib::seed_global(42);
+/// let d20 = rnglib::randrange(1..=20);
+/// ```
+pub fn seed_global(seed: u32) {
+    // the Mersenne Twister accepts every seed
+    GLOBAL.with(|rng| *rng.borrow_mut() = Random::seed(seed).unwrap());
+}
+
+/// Returns a random number in a given range using the global generator.
+/// 
+/// e.g.
+/// ```rust
+/// let value: u32 = rnglib::randrange(1..=6);
+/// ```
+pub fn randrange<R: ValidRandomRange<u32>>(range: R) -> u32 {
+    return GLOBAL.with(|rng| rng.borrow_mut().randrange(range));
+}
+
+/// Returns a random `f64` in the range [0, 1] using the global generator.
+/// 
+/// e.g.
+/// ```rust
+/// let value: f64 = rnglib::random();
+/// ```
+pub fn random() -> f64 {
+    return GLOBAL.with(|rng| rng.borrow_mut().random());
+}
+
+/// Returns a random number for a given gaussian distribution using the global generator.
+/// 
+/// e.g.
+/// ```rust
+/// let value: f64 = rnglib::gaussian(0.0, 1.0);
+/// ```
+pub fn gaussian(mean: f64, std_dev: f64) -> f64 {
+    return GLOBAL.with(|rng| rng.borrow_mut().gaussian(mean, std_dev));
+}
+
+/// Chooses a random value from a given slice using the global generator and returns a reference
+/// to it.
+/// 
+/// e.g.
+/// ```rust
+/// let list: Vec<String> = vec!["Hello".to_string(), "World".to_string()];
+/// let value: &String = rnglib::choose(&list);
+/// ```
+pub fn choose<G>(slice: &[G]) -> &G {
+    return GLOBAL.with(|rng| rng.borrow_mut().choose(slice));
+}
+
+/// Performs an inplace Fisher-Yates shuffle on the contents of a collection using the global
+/// generator.
+/// 
+/// e.g.
+/// ```rust
+/// let mut list: Vec<u32> = vec![1, 2, 3, 4];
+/// rnglib::shuffle(&mut list);
+/// ```
+pub fn shuffle<S: Shuffleable + ?Sized>(collection: &mut S) {
+    GLOBAL.with(|rng| rng.borrow_mut().shuffle(collection));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_global_reproducible() {
+        seed_global(10);
+        let first: Vec<u32> = (0..10).map(|_| randrange(0..100)).collect();
+        seed_global(10);
+        let second: Vec<u32> = (0..10).map(|_| randrange(0..100)).collect();
+        assert_eq!(first, second);
+
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let expected: Vec<u32> = (0..10).map(|_| rng.randrange(0..100)).collect();
+        assert_eq!(first, expected);
+    }
+
+    #[test]
+    fn seed_global_functions() {
+        seed_global(10);
+        let list = vec!["This", "is", "a", "test"];
+        let value = (random(), gaussian(0.0, 1.0), *choose(&list));
+        seed_global(10);
+        assert_eq!(value, (random(), gaussian(0.0, 1.0), *choose(&list)));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::algorithm::{RandomAlgorithm, StatefulAlgorithm, time_seed};
+use crate::splitmix::SplitMix32;
+
+// Implementation for the subtractive lagged Fibonacci generator described by Knuth
+// https://en.wikipedia.org/wiki/Lagged_Fibonacci_generator
+/// Subtractive lagged Fibonacci algorithm, with lags 55 and 24.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaggedFibonacci {
+    state: [u32; 55], // ring buffer with the last 55 numbers
+    index: usize // position of the oldest number
+}
+
+impl RandomAlgorithm for LaggedFibonacci {
+    type Seed = u32;
+    type Number = u32;
+    const NAME: &'static str = "LaggedFibonacci";
+    // 2^31 * (2^55 - 1)
+    const PERIOD_LOG2: u32 = 86;
+
+    fn new(seed: Self::Seed) -> Result<LaggedFibonacci, &'static str> {
+        let mut splitmix = SplitMix32::new(seed);
+        let mut state = [0; 55];
+
+        for value in state.iter_mut() {
+            *value = splitmix.next();
+        }
+
+        // the full period needs at least one odd number in the buffer
+        state[0] |= 1;
+
+        return Ok(LaggedFibonacci { state, index: 0 });
+    }
+
+    fn default() -> LaggedFibonacci {
+        return LaggedFibonacci::from_u64(time_seed()).unwrap();
+    }
+
+    fn next_raw(&mut self) -> Self::Number {
+        // x[n] = x[n - 55] - x[n - 24] mod 2^32
+        let value = self.state[self.index].wrapping_sub(self.state[(self.index + 31) % 55]);
+        self.state[self.index] = value;
+        self.index = (self.index + 1) % 55;
+
+        return value;
+    }
+}
+
+impl StatefulAlgorithm for LaggedFibonacci {
+    type State = ([u32; 55], usize);
+
+    fn state(&self) -> Self::State {
+        return (self.state, self.index);
+    }
+
+    fn from_state(state: Self::State) -> LaggedFibonacci {
+        return LaggedFibonacci { state: state.0, index: state.1 % 55 };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        if state.0 == [0; 55] {
+            return Err("at least one bit of the state must be initialized to non-zero");
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lagged_fibonacci_sequence() {
+        let mut random = LaggedFibonacci::new(10).unwrap();
+        let values: Vec<u32> = (0..5).map(|_| random.next_raw()).collect();
+        assert_eq!(values, vec![4184383244, 4226974749, 3258601814, 1068506982, 3344427882]);
+    }
+
+    #[test]
+    fn lagged_fibonacci_lags() {
+        let mut random = LaggedFibonacci::new(10).unwrap();
+        let values: Vec<u32> = (0..200).map(|_| random.next_raw()).collect();
+
+        for n in 55..200 {
+            assert_eq!(values[n], values[n - 55].wrapping_sub(values[n - 24]));
+        }
+    }
+}
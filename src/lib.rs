@@ -28,15 +28,35 @@
 //! Do not use pseudo-random number generation for passwords or cryptographic needs.
 mod values;
 mod algorithm;
+mod splitmix;
 mod xorshift;
 mod mersennetwister;
+mod xoshiro;
+mod chacha;
+mod laggedfibonacci;
+mod minstd;
+mod combined;
 mod rand;
+mod builder;
+mod weighted;
+#[cfg(feature = "std")]
+mod global;
 
-pub use values::{ValidRandomNumber, ValidRandomRange};
-pub use algorithm::RandomAlgorithm;
-pub use xorshift::{XORShift32, XORShift64, XORShift128, XORShift128Plus};
+pub use values::{ValidRandomNumber, ValidRandomRange, ValidRandomSeed, ValidRandomState};
+pub use values::Shuffleable;
+pub use algorithm::{RandomAlgorithm, StatefulAlgorithm};
+pub use xorshift::{XORShift32, XORShift64, XORShift128, XORShift128Plus, XORShift64Star};
 pub use mersennetwister::MersenneTwister;
-pub use rand::Random;
+pub use xoshiro::Xoshiro128PlusPlus;
+pub use chacha::ChaCha8;
+pub use laggedfibonacci::LaggedFibonacci;
+pub use minstd::MinStd;
+pub use combined::Combined;
+pub use rand::{Random, RandomState};
+pub use builder::RandomBuilder;
+pub use weighted::{WeightedSampler, weighted_sampler};
+#[cfg(feature = "std")]
+pub use global::{seed_global, randrange, random, gaussian, choose, shuffle};
 
 #[macro_export]
 /// The `random` macro can be used to create a default `Random` struct with the `MersenneTwister`
@@ -1,11 +1,11 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::fmt;
 
-use crate::values::ValidRandomRange;
-use crate::algorithm::RandomAlgorithm;
+use crate::algorithm::{RandomAlgorithm, StatefulAlgorithm, time_seed};
 
 // Implementation for the Mersenne Twister
 // https://en.wikipedia.org/wiki/Mersenne_Twister#Pseudocode
 /// Mersenne Twister algorithm.
+#[derive(Clone, PartialEq)]
 pub struct MersenneTwister {
     state: [u32; 624], // n = 624
     index: u32
@@ -35,11 +35,68 @@ impl MersenneTwister {
             self.state[i] = self.state[(i + 397) % 624] ^ y;
         }
     }
+
+    /// Creates a new Mersenne Twister seeded with an array of values, following the reference
+    /// `init_by_array` procedure, which spreads every value of the key over the whole state.
+    /// 
+    /// Returns an error if the key is empty.
+    pub fn new_from_array(key: &[u32]) -> Result<MersenneTwister, &'static str> {
+        if key.is_empty() {
+            return Err("key must have at least one value");
+        }
+
+        // http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/MT2002/emt19937ar.html
+        let mut random = MersenneTwister::new(19650218)?;
+        let state = &mut random.state;
+        let mut i = 1;
+        let mut j = 0;
+
+        for _ in 0..key.len().max(624) {
+            let previous = state[i - 1] ^ (state[i - 1] >> 30);
+            state[i] = (state[i] ^ previous.wrapping_mul(1664525))
+                .wrapping_add(key[j])
+                .wrapping_add(j as u32);
+            i += 1;
+            j = (j + 1) % key.len();
+
+            if i >= 624 {
+                state[0] = state[623];
+                i = 1;
+            }
+        }
+
+        for _ in 0..623 {
+            let previous = state[i - 1] ^ (state[i - 1] >> 30);
+            state[i] = (state[i] ^ previous.wrapping_mul(1566083941)).wrapping_sub(i as u32);
+            i += 1;
+
+            if i >= 624 {
+                state[0] = state[623];
+                i = 1;
+            }
+        }
+
+        // the most significant bit assures a non-zero initial state
+        state[0] = 0x80000000;
+
+        return Ok(random);
+    }
+}
+
+// The state is left out, 624 numbers are too many to be useful in a log.
+impl fmt::Debug for MersenneTwister {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return f.debug_struct("MersenneTwister")
+            .field("index", &self.index)
+            .finish_non_exhaustive();
+    }
 }
 
 impl RandomAlgorithm for MersenneTwister {
     type Seed = u32;
     type Number = u32;
+    const NAME: &'static str = "MersenneTwister";
+    const PERIOD_LOG2: u32 = 19937;
 
     // Coefficients:
     // w = 32 | n = 624        | m = 397 | r = 31         | a = 0x9908B0DF
@@ -56,7 +113,9 @@ impl RandomAlgorithm for MersenneTwister {
         for i in 1..624 {
             let idx = i as usize;
             // tmp = f * (state[i - 1] ^ (state[i - 1] >> w - 2)) + i
-            let tmp = 1812433253_u32.wrapping_mul(state[idx - 1] ^ (state[idx - 1] >> 30)) + i;
+            let tmp = 1812433253_u32
+                .wrapping_mul(state[idx - 1] ^ (state[idx - 1] >> 30))
+                .wrapping_add(i);
             // state[i] = tmp & d
             state[idx] = tmp & 0xFFFFFFFF;
         }
@@ -65,15 +124,10 @@ impl RandomAlgorithm for MersenneTwister {
     }
 
     fn default() -> MersenneTwister {
-        let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
-            Ok(duration) => duration.subsec_micros(),
-            Err(_) => 1
-        };
-
-        return MersenneTwister::new(seed).unwrap();
+        return MersenneTwister::from_u64(time_seed()).unwrap();
     }
 
-    fn randrange<R: ValidRandomRange<Self::Number>>(&mut self, range: R) -> Self::Number {
+    fn next_raw(&mut self) -> Self::Number {
         // if index >= n
         if self.index >= 624 {
             self.twist();
@@ -87,11 +141,32 @@ impl RandomAlgorithm for MersenneTwister {
         x ^= (x << 7) & 0x9D2C5680;
         // x ^= (x << t) & c
         x ^= (x << 15) & 0xEFC60000;
-        x ^= x >> 1;
+        // x ^= x >> l
+        x ^= x >> 18;
 
         self.index += 1;
 
-        return (x & 0xFFFFFFFF) % (range._end() - range._start()) + range._start();
+        return x & 0xFFFFFFFF;
+    }
+}
+
+impl StatefulAlgorithm for MersenneTwister {
+    type State = ([u32; 624], u32);
+
+    fn state(&self) -> Self::State {
+        return (self.state, self.index);
+    }
+
+    fn from_state(state: Self::State) -> MersenneTwister {
+        return MersenneTwister { state: state.0, index: state.1 };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        if state.0 == [0; 624] {
+            return Err("at least one bit of the state must be initialized to non-zero");
+        }
+
+        return Ok(());
     }
 }
 
@@ -103,6 +178,62 @@ mod tests {
     fn mersenne_twister_random_value() {
         let mut random = MersenneTwister::new(10).unwrap();
         let value = random.randrange(1..5);
-        assert_eq!(value, 3);
+        assert_eq!(value, 2);
+
+        // first number of the reference implementation with its default seed
+        let mut random = MersenneTwister::new(5489).unwrap();
+        assert_eq!(random.next_raw(), 3499211612);
+    }
+
+    #[test]
+    fn mersenne_twister_from_array() {
+        let mut random = MersenneTwister::new_from_array(&[0x123, 0x234, 0x345, 0x456]).unwrap();
+
+        // first numbers of mt19937ar.out
+        let values: Vec<u32> = (0..5).map(|_| random.next_raw()).collect();
+        assert_eq!(values, vec![1067595299, 955945823, 477289528, 4107218783, 4228976476]);
+        assert!(MersenneTwister::new_from_array(&[]).is_err());
+    }
+
+    #[test]
+    fn mersenne_twister_default_differs() {
+        assert_ne!(MersenneTwister::default().next_raw(), MersenneTwister::default().next_raw());
+    }
+
+    #[test]
+    fn mersenne_twister_default_in_loop() {
+        let outputs: Vec<u32> = (0..100).map(|_| MersenneTwister::default().next_raw()).collect();
+
+        assert!(outputs.iter().any(|&output| output != outputs[0]));
+    }
+
+    #[test]
+    fn mersenne_twister_from_u64() {
+        let mut random = MersenneTwister::from_u64(10).unwrap();
+        let mut other = MersenneTwister::from_u64(10 + (1 << 32)).unwrap();
+        assert_ne!(random.next_raw(), other.next_raw());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn mersenne_twister_reversed_range() {
+        let mut random = MersenneTwister::new(10).unwrap();
+        let mut reversed = MersenneTwister::new(10).unwrap();
+
+        for _ in 0..100 {
+            let value = reversed.randrange(10..3);
+            assert!((3..10).contains(&value));
+            assert_eq!(value, random.randrange(3..10));
+            assert_eq!(reversed.randrange(7..=2), random.randrange(2..=7));
+        }
+    }
+
+    #[test]
+    fn mersenne_twister_full_range() {
+        let mut random = MersenneTwister::new(10).unwrap();
+        random.randrange(0..=u32::MAX);
+        random.randrange(..);
+        let value = random.randrange(u32::MAX - 1..=u32::MAX);
+        assert!(value >= u32::MAX - 1);
     }
 }
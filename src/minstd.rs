@@ -0,0 +1,99 @@
+use crate::algorithm::{RandomAlgorithm, StatefulAlgorithm, time_seed};
+
+// Implementation for the Park-Miller minimal standard linear congruential generator
+// https://en.wikipedia.org/wiki/Lehmer_random_number_generator#Parameters_in_common_use
+/// Park-Miller minimal standard algorithm.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinStd {
+    state: u32
+}
+
+impl MinStd {
+    // Coefficients:
+    // m = 2^31 - 1 | a = 16807
+    const MODULUS: u64 = 2147483647;
+    const MULTIPLIER: u64 = 16807;
+}
+
+impl RandomAlgorithm for MinStd {
+    type Seed = u32;
+    type Number = u32;
+    const NAME: &'static str = "MinStd";
+    // 2^31 - 2
+    const PERIOD_LOG2: u32 = 31;
+
+    fn new(seed: Self::Seed) -> Result<MinStd, &'static str> {
+        MinStd::validate_seed(&seed)?;
+        return Ok(MinStd { state: (u64::from(seed) % MinStd::MODULUS) as u32 });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
+        if u64::from(*seed) % MinStd::MODULUS == 0 {
+            return Err("seed must not be zero or a multiple of 2^31 - 1");
+        }
+
+        return Ok(());
+    }
+
+    fn default() -> MinStd {
+        return match MinStd::from_u64(time_seed()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => MinStd { state: 1 }
+        };
+    }
+
+    fn next_raw(&mut self) -> Self::Number {
+        // state = a * state mod m
+        self.state = (u64::from(self.state) * MinStd::MULTIPLIER % MinStd::MODULUS) as u32;
+
+        return self.state;
+    }
+
+    // The numbers are in the range [1, 2^31 - 2], so 0 and 2^31 - 1 are never generated.
+    fn raw_bits() -> u32 {
+        return 31;
+    }
+}
+
+impl StatefulAlgorithm for MinStd {
+    type State = u32;
+
+    fn state(&self) -> Self::State {
+        return self.state;
+    }
+
+    fn from_state(state: Self::State) -> MinStd {
+        return MinStd { state };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        if *state == 0 || u64::from(*state) >= MinStd::MODULUS {
+            return Err("state must be between 1 and 2^31 - 2");
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minstd_sequence() {
+        let mut random = MinStd::new(1).unwrap();
+        let values: Vec<u32> = (0..3).map(|_| random.next_raw()).collect();
+        assert_eq!(values, vec![16807, 282475249, 1622650073]);
+
+        // check value given by Park and Miller for the 10000th number
+        let mut random = MinStd::new(1).unwrap();
+        assert_eq!((0..10000).map(|_| random.next_raw()).last(), Some(1043618065));
+    }
+
+    #[test]
+    fn minstd_validate_seed() {
+        assert!(MinStd::new(0).is_err());
+        assert!(MinStd::new(2147483647).is_err());
+        assert_eq!(MinStd::new(2147483648).unwrap(), MinStd::new(1).unwrap());
+    }
+}
@@ -1,7 +1,44 @@
 use std::collections::HashSet;
+use std::fmt;
 
-use crate::algorithm::RandomAlgorithm;
-use crate::values::{ValidRandomNumber, ValidRandomRange};
+use crate::algorithm::{RandomAlgorithm, StatefulAlgorithm, time_seed};
+use crate::combined::Combined;
+use crate::mersennetwister::MersenneTwister;
+use crate::builder::RandomBuilder;
+use crate::splitmix::SplitMix64;
+use crate::values::{ValidRandomNumber, ValidRandomRange, ValidRandomSeed, ValidRandomState};
+use crate::values::Shuffleable;
+
+/// Struct `RandomState`, the full state of a `Random` struct, as returned by `Random::state`.
+/// 
+/// Besides the state of the underlying algorithm it keeps the values the `Random` struct carries
+/// between calls, like the spare gaussian number, the last byte of `rand_graycode_byte` or the key
+/// of `value_noise_2d`, so a restored generator continues exactly where the original one was.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomState<S> {
+    /// State of the underlying algorithm.
+    pub algorithm: S,
+    spare: Option<f64>,
+    gray_byte: u8,
+    noise_key: u64
+}
+
+impl<S: ValidRandomState> ValidRandomState for RandomState<S> {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        self.algorithm.write_bytes(bytes);
+        self.spare.write_bytes(bytes);
+        self.gray_byte.write_bytes(bytes);
+        self.noise_key.write_bytes(bytes);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(RandomState<S>, &[u8])> {
+        let (algorithm, rest) = S::read_bytes(bytes)?;
+        let (spare, rest) = Option::<f64>::read_bytes(rest)?;
+        let (gray_byte, rest) = u8::read_bytes(rest)?;
+        let (noise_key, rest) = u64::read_bytes(rest)?;
+        return Some((RandomState { algorithm, spare, gray_byte, noise_key }, rest));
+    }
+}
 
 /// Struct `Random`, used to generate multiple random values with the given algorithm, or use them
 /// to do something, like a shuffle.
@@ -15,7 +52,14 @@ use crate::values::{ValidRandomNumber, ValidRandomRange};
 pub struct Random<T>
     where T: RandomAlgorithm
 {
-    algorithm: T
+    algorithm: T,
+    seed: Option<T::Seed>, // only known when it was explicitly given
+    spare: Option<f64>, // second value of the last gaussian pair
+    gaussian_cache: bool, // whether the spare value is used
+    gray_byte: u8, // last value of rand_graycode_byte
+    digest: Option<u64>, // only kept by audited generators
+    draws: u64, // calls made to the algorithm
+    noise_key: u64 // base of value_noise_2d, taken from the seed or the generator it came from
 }
 
 impl<T> Random<T>
@@ -28,7 +72,33 @@ impl<T> Random<T>
     /// let mut rng: Random<MersenneTwister> = Random::new();
     /// ```
     pub fn new() -> Random<T> {
-        return Random { algorithm: T::default() };
+        return Random::from_algorithm(T::default(), None, time_seed());
+    }
+
+    /// Creates a new `Random` struct with a default seed for the underlying algorithm, which keeps
+    /// a digest of every value it generates. See `digest`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new_audited();
+    /// ```
+    pub fn new_audited() -> Random<T> {
+        let mut rng = Random::new();
+        rng.set_audited(true);
+
+        return rng;
+    }
+
+    /// Returns a `RandomBuilder` to configure a new `Random` struct.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let rng: Random<MersenneTwister> = Random::builder().seed(42).build().unwrap();
+    /// ```
+    pub fn builder() -> RandomBuilder<T> {
+        return RandomBuilder::new();
     }
 
     /// Creates a new `Random` struct with a given seed for the underlying algorithm.
@@ -37,239 +107,3601 @@ impl<T> Random<T>
     /// let mut rng: Random<MersenneTwister> = Random::seed(42);
     /// ```
     pub fn seed(seed: T::Seed) -> Result<Random<T>, &'static str> {
-        return Ok(Random { algorithm: T::new(seed)? });
+        return Ok(Random::from_algorithm(T::new(seed)?, Some(seed), Random::<T>::seed_key(&seed)));
     }
 
-    /// Returns a random number in a given range.
+    /// Creates a new `Random` struct with a `u64` seed for the underlying algorithm, whatever the
+    /// type of its seed is.
     /// 
     /// e.g.
     /// ```rust
-    /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let value: u32 = rng.randrange(1..=6);
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::from_u64(1 << 40).unwrap();
     /// ```
-    pub fn randrange<R: ValidRandomRange<T::Number>>(&mut self, range: R) -> T::Number {
-        return self.algorithm.randrange(range);
+    pub fn from_u64(seed: u64) -> Result<Random<T>, &'static str> {
+        let algorithm = T::from_u64(seed)?;
+        let seed = T::Seed::from_u64(seed);
+
+        return Ok(Random::from_algorithm(algorithm, Some(seed), Random::<T>::seed_key(&seed)));
     }
 
-    /// Returns a random `f64` in the range [0, 1]
+    /// Creates a new `Random` struct seeded with the 64 bits FNV-1a hash of a string, passed to
+    /// `from_u64`, so that seeds can be shared as words or phrases.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::seed_from_str("glacier cave").unwrap();
+    /// ```
+    pub fn seed_from_str(s: &str) -> Result<Random<T>, &'static str> {
+        return Random::from_u64(Random::<T>::fnv1a(s.as_bytes()));
+    }
+
+    /// Creates a new `Random` struct, independent from the current one, seeded with the next
+    /// numbers of the current one mixed through SplitMix64.
+    /// 
+    /// The current generator advances too, so splitting it repeatedly gives distinct generators.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::seed(42).unwrap();
+    /// let mut workers: Vec<Random<MersenneTwister>> = (0..4).map(|_| rng.split()).collect();
+    /// ```
+    pub fn split(&mut self) -> Random<T> {
+        loop {
+            let value = Random::raw_u64(&mut self.algorithm);
+            self.draws += u64::from(64_u32.div_ceil(T::raw_bits()));
+
+            // the mixed seed may still be an invalid one for the algorithm
+            if let Ok(algorithm) = T::from_u64(value) {
+                return Random::from_algorithm(algorithm, None, SplitMix64::new(value).next());
+            }
+        }
+    }
+
+    /// Creates a new `Random` struct whose numbers are the ones of two generators combined with a
+    /// XOR, so that patterns in either of them get hidden.
+    /// 
+    /// Its seed is only known if both generators had one.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister, Combined};
+    /// let first: Random<MersenneTwister> = Random::seed(1).unwrap();
+    /// let second: Random<MersenneTwister> = Random::seed(2).unwrap();
+    /// let mut rng: Random<Combined<MersenneTwister>> = Random::combine(first, second);
+    /// ```
+    pub fn combine(first: Random<T>, second: Random<T>) -> Random<Combined<T>> {
+        let (seed, noise_key) = match (first.seed, second.seed) {
+            (Some(first), Some(second)) => {
+                (Some((first, second)), Random::<Combined<T>>::seed_key(&(first, second)))
+            },
+            _ => (None, SplitMix64::new(first.noise_key).next() ^ second.noise_key)
+        };
+        let algorithm = Combined::pair(first.algorithm, second.algorithm);
+
+        return Random::from_algorithm(algorithm, seed, noise_key);
+    }
+
+    /// Consumes the `Random` struct and returns an endless iterator of the raw numbers of the
+    /// underlying algorithm.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let rng: Random<MersenneTwister> = Random::new();
+    /// let values: Vec<u32> = rng.into_stream().take(10).collect();
+    /// ```
+    pub fn into_stream(self) -> impl Iterator<Item = T::Number> {
+        let mut algorithm = self.algorithm;
+        return std::iter::from_fn(move || Some(algorithm.next_raw()));
+    }
+
+    /// Returns the name of the underlying algorithm.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// assert_eq!(Random::<MersenneTwister>::algorithm_name(), "MersenneTwister");
+    /// ```
+    pub fn algorithm_name() -> &'static str {
+        return T::NAME;
+    }
+
+    /// Returns the base 2 logarithm of the period of the underlying algorithm, or zero if it's
+    /// unknown.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// assert_eq!(Random::<MersenneTwister>::period_log2(), 19937);
+    /// ```
+    pub fn period_log2() -> u32 {
+        return T::PERIOD_LOG2;
+    }
+
+    /// Returns the seed the generator was created with, or `None` if it wasn't explicitly given,
+    /// like with `new`, `split` or `restore`. Seeds given to `from_u64` are returned already mixed
+    /// into the seed type of the algorithm.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let rng: Random<MersenneTwister> = Random::seed(42).unwrap();
+    /// assert_eq!(rng.seed_used(), Some(42));
+    /// ```
+    pub fn seed_used(&self) -> Option<T::Seed> {
+        return self.seed;
+    }
+
+    /// Returns a seed that gives the same sequence when passed to `Random::seed`, or `None` if
+    /// the generator wasn't created from a known seed.
+    /// 
+    /// It's the seed the generator was created with, as returned by `seed_used`, so the new
+    /// generator replays the sequence from its start, not from the current position. Use `state`
+    /// or `to_hex` to continue from the current position instead.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::from_u64(42).unwrap();
+    /// let first: u32 = rng.randrange(..);
+    /// let mut replay: Random<MersenneTwister> = Random::seed(rng.reproducible_seed().unwrap())
+    ///     .unwrap();
+    /// assert_eq!(replay.randrange(..), first);
+    /// ```
+    pub fn reproducible_seed(&self) -> Option<T::Seed> {
+        return self.seed_used();
+    }
+
+    /// Returns a value in the range [0, 1] for the given coordinates, which is always the same for
+    /// the same coordinates and seed, no matter which numbers were generated before.
+    /// 
+    /// The values only depend on the seed the generator was created with. Generators created with
+    /// `split` or `stream_for` take theirs from the parent generator, restored ones keep the one
+    /// of the saved state, and the ones created with `new` use a different one each run.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let rng: Random<MersenneTwister> = Random::seed(42).unwrap();
+    /// let height: f64 = rng.value_noise_2d(10, -3);
+    /// assert_eq!(height, rng.value_noise_2d(10, -3));
+    /// ```
+    pub fn value_noise_2d(&self, x: i64, y: i64) -> f64 {
+        let hash = SplitMix64::new(self.noise_key ^ x as u64).next();
+        let hash = SplitMix64::new(hash ^ y as u64).next();
+
+        // the highest 53 bits fill the mantissa of the f64
+        return (hash >> 11) as f64 / ((1_u64 << 53) - 1) as f64;
+    }
+
+    /// Returns a digest of every value generated so far, so two runs can be checked to be the
+    /// same. Only audited generators, created with `new_audited` or the `audited` option of the
+    /// builder, keep a digest; the rest always return 0.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::builder().seed(42).audited(true).build()
+    ///     .unwrap();
+    /// rng.randrange(1..=6);
+    /// let digest: u64 = rng.digest();
+    /// ```
+    pub fn digest(&self) -> u64 {
+        return self.digest.unwrap_or(0);
+    }
+
+    /// Returns how many times the underlying algorithm was called to generate values so far. Each
+    /// call to the `randrange` method of the algorithm counts as one, like its default does.
     /// 
     /// e.g.
     /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
     /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let value: f64 = rng.random();
+    /// rng.randrange(1..=6);
+    /// assert_eq!(rng.draw_count(), 1);
     /// ```
-    pub fn random(&mut self) -> f64 {
-        let value: T::Number = self.algorithm.randrange(T::Number::zero()..T::Number::max());
-        return value.to_f64() / T::Number::max().to_f64();
+    pub fn draw_count(&self) -> u64 {
+        return self.draws;
     }
 
-    /// Returns a random numer for a given uniform distribution.
+    /// Returns a random number in a given range.
     /// 
-    /// It receives a lower and upper bounds.
+    /// A range whose end is smaller than its start is treated as if its bounds were swapped, e.g.
+    /// `10..3` behaves like `3..10`.
     /// 
     /// e.g.
     /// ```rust
     /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let value: f64 = rng.uniform(1, 6);
+    /// let value: u32 = rng.randrange(1..=6);
     /// ```
-    pub fn uniform(&mut self, lower: T::Number, upper: T::Number) -> f64 {
-        return lower.to_f64() + (upper - lower).to_f64() * self.random();
+    pub fn randrange<R: ValidRandomRange<T::Number>>(&mut self, range: R) -> T::Number {
+        self.draws += 1;
+        let value = self.algorithm.randrange(range);
+        return self.audit(value);
     }
 
-    /// Returns a random number for a given triangular distribution.
+    /// Returns a random number in the whole range of the number type of the algorithm, built
+    /// directly from its raw numbers without fitting them to a range.
     /// 
-    /// It receives a lower and upper bounds, as well as the mode.
+    /// Algorithms that generate fewer bits than their number type have are called as many times as
+    /// needed to fill every bit.
     /// 
     /// e.g.
     /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
     /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let value: f64 = rng.triangular(1, 8, 5);
+    /// let value: u32 = rng.full();
     /// ```
-    pub fn triangular(&mut self, lower: T::Number, upper: T::Number, mode: T::Number) -> f64 {
-        let value = self.random();
+    pub fn full(&mut self) -> T::Number {
+        let mut value = self.next_raw();
+        let mut bits = T::raw_bits();
 
-        if value <= (mode - lower).to_f64() / (upper - lower).to_f64() {
-            return (value * ((upper - lower).to_f64() * (mode - lower).to_f64())).sqrt()
-                + lower.to_f64();
+        while bits < T::Number::bits() {
+            value = (value << T::raw_bits()).wrapping_add(self.next_raw());
+            bits += T::raw_bits();
         }
 
-        return upper.to_f64()
-            - ((1.0 - value) * ((upper - lower).to_f64() * (upper - mode).to_f64())).sqrt();
+        return self.audit(value);
     }
 
-    /// Returns a `u8` vector of length `amount` with random values.
+    /// Returns a random number whose lowest `width` bits are random and the rest are zero, that is,
+    /// a number in the range [0, 2^width) without any bias.
+    /// 
+    /// The bits are the highest ones of `full`, moved down. Panics if `width` is bigger than the
+    /// bits of the number type of the algorithm.
     /// 
     /// e.g.
     /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
     /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let bytes: Vec<u8> = rng.randbytes(10);
+    /// let nibble: u32 = rng.bits(4);
+    /// assert!(nibble < 16);
     /// ```
-    /// 
-    /// Warning: do not use this function for secure random bytes generation.
-    pub fn randbytes(&mut self, amount: T::Number) -> Vec<u8> {
-        let mut bytes: Vec<u8> = vec![0; amount.to_usize()];
+    pub fn bits(&mut self, width: u32) -> T::Number {
+        assert!(width <= T::Number::bits(), "width can't be bigger than the bits of the number");
 
-        for i in 0..amount.to_usize() {
-            bytes[i] = self.randrange(T::Number::zero()..=T::Number::byte_max()).to_u8();
+        // shifting by every bit of the number would overflow
+        if width == 0 {
+            return T::Number::zero();
         }
 
-        return bytes;
+        return self.full() >> (T::Number::bits() - width);
     }
 
-    /// Chooses a random value from a given vector and returns a reference to it.
+    /// Returns a random number in a given range, eased by raising a uniform number in [0, 1] to
+    /// `power` before fitting it to the range.
+    /// 
+    /// A `power` bigger than 1 makes the lowest numbers of the range more likely, while a `power`
+    /// smaller than 1 makes the highest ones more likely. Like `randrange`, the bounds are swapped
+    /// if the end is smaller than the start, and it panics if the range is empty or `power` isn't
+    /// positive.
     /// 
     /// e.g.
     /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
     /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let list: Vec<String> = vec!["Hello".to_string(), "World".to_string()];
-    /// let value: &String = rng.choose(&list);
+    /// // mostly small loot
+    /// let gold: u32 = rng.randrange_eased(1..=1000, 3.0);
     /// ```
-    pub fn choose<'a, G>(&'a mut self, vector: &'a Vec<G>) -> &G {
-        let end: T::Number = T::Number::from_usize(vector.len());
-        let index = self.randrange(T::Number::zero()..end).to_usize();
-        return &vector[index];
+    pub fn randrange_eased<R: ValidRandomRange<T::Number>>(
+        &mut self, range: R, power: f64
+    ) -> T::Number {
+        assert!(power > 0.0, "power must be positive");
+
+        let (start, end) = if range._end() < range._start() {
+            (range._end(), range._start())
+        } else {
+            (range._start(), range._end())
+        };
+        let mut width = end.wrapping_sub(start).unsigned_to_f64();
+
+        if range._inclusive() {
+            width += 1.0;
+        }
+
+        assert!(width > 0.0, "can't get a random number from an empty range");
+
+        let offset = (self.random().powf(power) * width).floor().min(width - 1.0);
+        return start.wrapping_add(T::Number::from_usize(offset as usize));
     }
 
-    // The Fisher-Yates shuffle as described in
-    // https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle
-    /// Performs an inplace Fisher-Yates shuffle on the contents of a vector.
+    /// Returns an array of `N` random numbers in a given range.
     /// 
     /// e.g.
     /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
     /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let list: Vec<String> = vec!["Hello".to_string(), "World".to_string()];
-    /// rng.shuffle(&list);
+    /// let [r, g, b] = rng.randrange_n(0..256);
     /// ```
-    pub fn shuffle<G>(&mut self, vector: &mut Vec<G>) {
-        let mut items = vector.len() - 1;
+    pub fn randrange_n<const N: usize, R: ValidRandomRange<T::Number> + Clone>(
+        &mut self, range: R
+    ) -> [T::Number; N] {
+        return std::array::from_fn(|_| self.randrange(range.clone()));
+    }
 
-        while items > 0 {
-            let pos = self.randrange(
-                T::Number::zero()..=T::Number::from_usize(items)
-            ).to_usize();
+    /// Returns a grid of `rows` rows and `cols` columns of random numbers in a given range.
+    /// 
+    /// The grid is filled row by row.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let heights: Vec<Vec<u32>> = rng.rand_grid(64, 64, 0..=255);
+    /// ```
+    pub fn rand_grid<R: ValidRandomRange<T::Number> + Clone>(
+        &mut self, rows: usize, cols: usize, range: R
+    ) -> Vec<Vec<T::Number>> {
+        let mut grid: Vec<Vec<T::Number>> = Vec::with_capacity(rows);
 
-            if pos != items {
-                vector.swap(pos, items);
-            }
-    
-            items -= 1;
+        for _ in 0..rows {
+            grid.push((0..cols).map(|_| self.randrange(range.clone())).collect());
         }
+
+        return grid;
     }
 
-    /// Returns a `Result` containing a random sample of length `amount` from the contents of a
-    /// given vector.
+    /// Returns how many of `samples` random numbers in a given range fall in each of `buckets`
+    /// buckets of equal width, useful to check that numbers are spread evenly.
     /// 
-    /// The given `amount` can't be bigger than the total population.
+    /// Panics if `buckets` is zero.
     /// 
     /// e.g.
     /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
     /// let mut rng: Random<MersenneTwister> = Random::new();
-    /// let list: Vec<String> = vec!["Hello".to_string(), "World".to_string()];
-    /// let sample: Result<Vec<&String>, &'static str> = rng.sample(&list, 1);
+    /// let counts: Vec<usize> = rng.histogram(0..100, 10000, 10);
+    /// assert_eq!(counts.iter().sum::<usize>(), 10000);
     /// ```
-    pub fn sample<'a, G>(
-        &'a mut self, vector: &'a Vec<G>, amount: usize
-    ) -> Result<Vec<&G>, &'static str> {
-        let length = vector.len();
+    pub fn histogram<R: ValidRandomRange<T::Number> + Clone>(
+        &mut self, range: R, samples: usize, buckets: usize
+    ) -> Vec<usize> {
+        assert!(buckets > 0, "there must be at least one bucket");
 
-        if amount > length {
-            return Err("can't get a sample bigger than the population");
-        }
+        let (start, end) = if range._end() < range._start() {
+            (range._end(), range._start())
+        } else {
+            (range._start(), range._end())
+        };
+        let mut width = end.wrapping_sub(start).unsigned_to_f64();
 
-        let mut items: usize = 0;
-        let mut positions: HashSet<usize> = HashSet::with_capacity(amount);
-        let mut selected: Vec<&G> = Vec::with_capacity(amount);
+        if range._inclusive() {
+            width += 1.0;
+        }
 
-        while items < amount {
-            let pos = self.randrange(
-                T::Number::zero()..T::Number::from_usize(length)
-            ).to_usize();
+        let mut counts: Vec<usize> = vec![0; buckets];
 
-            if !positions.contains(&pos) {
-                positions.insert(pos);
-                items += 1;
-            }
+        for _ in 0..samples {
+            let value = self.randrange(range.clone());
+            let offset = value.wrapping_sub(start).unsigned_to_f64();
+            let bucket = (offset / width * buckets as f64) as usize;
+            // rounding errors may leave the last numbers past the last bucket
+            counts[bucket.min(buckets - 1)] += 1;
         }
 
-        for pos in &positions {
-            selected.push(&vector[*pos]);
+        return counts;
+    }
+
+    /// Returns a `Result` containing a random number in a given range.
+    /// 
+    /// Unlike `randrange`, which panics on empty ranges and swaps the bounds of reversed ones, it
+    /// returns an error when the range is empty or its end is smaller than its start.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: Result<u32, &'static str> = rng.try_randrange(5..5);
+    /// assert!(value.is_err());
+    /// ```
+    pub fn try_randrange<R: ValidRandomRange<T::Number>>(
+        &mut self, range: R
+    ) -> Result<T::Number, &'static str> {
+        let width = match T::Number::checked_width(range._start(), range._end()) {
+            Some(width) => width,
+            None => return Err("range end can't be smaller than its start")
+        };
+
+        if width == T::Number::zero() && !range._inclusive() {
+            return Err("can't get a random number from an empty range");
         }
 
-        return Ok(selected);
+        return Ok(self.randrange(range));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::mersennetwister::MersenneTwister;
+    /// Returns a random index for a collection of length `len`, or `None` if `len` is zero.
+    /// 
+    /// Unlike taking the result of `randrange` modulo `len`, every index is equally likely.
+    /// 
+    /// Panics if `len` is bigger than the amount of numbers the algorithm can generate.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let list: Vec<u32> = vec![4, 8, 15, 16, 23, 42];
+    /// let index: usize = rng.index(list.len()).unwrap();
+    /// println!("Picked {}", list[index]);
+    /// ```
+    pub fn index(&mut self, len: usize) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
 
-    #[test]
-    fn randrange() {
-        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
-        let value = rng.randrange(0..10);
-        assert_eq!(value, 6);
-    }
+        let raw_max = T::Number::max() >> (T::Number::bits() - T::raw_bits());
+        assert!(len - 1 <= raw_max.to_usize(), "length is bigger than the generator's range");
 
-    #[test]
-    fn random() {
-        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
-        let value = rng.random();
-        assert_eq!(value, 0.6555146273820462);
-    }
+        let bound = T::Number::from_usize(len);
+        // the raw values over the last multiple of `bound` would favour the lowest indices
+        let excess = (raw_max % bound + T::Number::one()) % bound;
+        let limit = raw_max - excess;
 
-    #[test]
-    fn uniform() {
-        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
-        let value = rng.uniform(1, 2);
-        assert_eq!(value, 1.6555146273820462);
-    }
+        loop {
+            let value = self.next_raw();
 
-    #[test]
-    fn triangular() {
-        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
-        let value = rng.triangular(1, 7, 4);
-        assert_eq!(value, 4.5098721504462524);
+            if value <= limit {
+                self.audit(value);
+                return Some(value.unsigned_rem(bound).to_usize());
+            }
+        }
     }
 
-    #[test]
-    fn randbytes() {
-        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
-        let value = rng.randbytes(4);
-        assert_eq!(value, vec![126, 210, 236, 124]);
+    /// Returns a random `f64` in the range [0, 1]
+    /// 
+    /// e.g.
+    /// ```rust
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.random();
+    /// ```
+    pub fn random(&mut self) -> f64 {
+        self.draws += 1;
+        // scaled by the biggest raw number, algorithms may not fill every bit of `Number`
+        let raw_max = T::Number::max() >> (T::Number::bits() - T::raw_bits());
+        let value: T::Number = self.algorithm.randrange(T::Number::zero()..=raw_max);
+        self.audit(value);
+        return value.to_f64() / raw_max.to_f64();
     }
 
-    #[test]
-    fn choose() {
-        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
-        let vector = vec![
-            "This".to_string(), "is".to_string(), "a".to_string(), "test".to_string()
-        ];
-        let chosen = rng.choose(&vector);
-        assert_eq!(chosen, "a");
+    /// Returns a random numer for a given uniform distribution.
+    /// 
+    /// It receives a lower and upper bounds.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.uniform(1, 6);
+    /// ```
+    pub fn uniform(&mut self, lower: T::Number, upper: T::Number) -> f64 {
+        return lower.to_f64() + (upper - lower).to_f64() * self.random();
     }
 
-    #[test]
-    fn shuffle() {
-        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
-        let mut vector = vec![
-            "This".to_string(), "is".to_string(), "a".to_string(), "test".to_string()
-        ];
-        rng.shuffle(&mut vector);
-        assert_eq!(vector, vec![
-            "is".to_string(), "This".to_string(), "test".to_string(), "a".to_string()
-        ]);
+    /// Returns the result of applying a given function to a random `f64` in the range [0, 1], as
+    /// returned by `random`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// // exponential distribution with a rate of 2
+    /// let value: f64 = rng.map_uniform(|u| -(1.0 - u).ln() / 2.0);
+    /// ```
+    pub fn map_uniform<F: Fn(f64) -> f64>(&mut self, f: F) -> f64 {
+        return f(self.random());
     }
 
-    #[test]
-    fn sample() {
+    /// Returns a random `f64` in the closed interval `[low, high]`.
+    /// 
+    /// Like `randrange`, the bounds are swapped if `high` is smaller than `low`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let temperature: f64 = rng.range_f64(-5.5, 30.25);
+    /// ```
+    pub fn range_f64(&mut self, low: f64, high: f64) -> f64 {
+        let (low, high) = if high < low { (high, low) } else { (low, high) };
+        return low + (high - low) * self.random();
+    }
+
+    /// Returns the `degree + 1` coefficients of a random polynomial of the given degree, each one
+    /// a random `f64` in the closed interval `[low, high]` like with `range_f64`.
+    /// 
+    /// The coefficients start from the constant term.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let coefficients: Vec<f64> = rng.rand_coefficients(3, -1.0, 1.0);
+    /// ```
+    pub fn rand_coefficients(&mut self, degree: usize, low: f64, high: f64) -> Vec<f64> {
+        return (0..=degree).map(|_| self.range_f64(low, high)).collect();
+    }
+
+    /// Returns a random Unix timestamp, in seconds, in the range `[start_secs, end_secs)`.
+    /// 
+    /// The timestamp is built from random bytes, so it covers the whole range even when the
+    /// algorithm generates numbers smaller than a `u64`. Like `randrange`, the bounds are swapped
+    /// if `end_secs` is smaller than `start_secs`, and it panics if both are equal.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// // some time during 2024
+    /// let timestamp: u64 = rng.rand_timestamp(1_704_067_200, 1_735_689_600);
+    /// ```
+    pub fn rand_timestamp(&mut self, start_secs: u64, end_secs: u64) -> u64 {
+        let (start, end) = if end_secs < start_secs {
+            (end_secs, start_secs)
+        } else {
+            (start_secs, end_secs)
+        };
+        assert!(start != end, "can't get a random number from an empty range");
+
+        let width = end - start;
+        // the values over the last multiple of `width` would favour the earliest timestamps
+        let limit = u64::MAX - (u64::MAX % width + 1) % width;
+
+        loop {
+            let value = u64::from_le_bytes(self.rand_array());
+
+            if value <= limit {
+                return start + value % width;
+            }
+        }
+    }
+
+    /// Returns a random number for a given triangular distribution.
+    /// 
+    /// It receives a lower and upper bounds, as well as the mode.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.triangular(1, 8, 5);
+    /// ```
+    pub fn triangular(&mut self, lower: T::Number, upper: T::Number, mode: T::Number) -> f64 {
+        let value = self.random();
+
+        if value <= (mode - lower).to_f64() / (upper - lower).to_f64() {
+            return (value * ((upper - lower).to_f64() * (mode - lower).to_f64())).sqrt()
+                + lower.to_f64();
+        }
+
+        return upper.to_f64()
+            - ((1.0 - value) * ((upper - lower).to_f64() * (upper - mode).to_f64())).sqrt();
+    }
+
+    /// Returns a random number for a given gaussian (normal) distribution.
+    /// 
+    /// It receives the mean and the standard deviation of the distribution.
+    /// 
+    /// Numbers are generated in pairs, so every other call uses the second number of the last
+    /// pair instead of drawing new values, unless the cache was disabled with `RandomBuilder`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.gaussian(0.0, 1.0);
+    /// ```
+    pub fn gaussian(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let z = match self.spare.take() {
+            Some(z) => z,
+            None => {
+                let (z0, z1) = self.standard_gaussian_pair();
+
+                if self.gaussian_cache {
+                    self.spare = Some(z1);
+                }
+
+                z0
+            }
+        };
+
+        return mean + std_dev * z;
+    }
+
+    /// Returns both random numbers of a pair for a given gaussian distribution.
+    /// 
+    /// Unlike `gaussian`, it doesn't keep a spare number, and always draws a new pair.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (x, y) = rng.gaussian_pair(0.0, 1.0);
+    /// ```
+    pub fn gaussian_pair(&mut self, mean: f64, std_dev: f64) -> (f64, f64) {
+        let (z0, z1) = self.standard_gaussian_pair();
+        return (mean + std_dev * z0, mean + std_dev * z1);
+    }
+
+    /// Returns a random number for a mixture of two gaussian distributions, drawn from the first
+    /// one with a probability of `weight1` and from the second one otherwise.
+    /// 
+    /// Panics if `weight1` isn't in the range [0, 1].
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.gaussian_mixture(0.3, -5.0, 1.0, 5.0, 2.0);
+    /// ```
+    pub fn gaussian_mixture(
+        &mut self, weight1: f64, mean1: f64, sd1: f64, mean2: f64, sd2: f64
+    ) -> f64 {
+        assert!((0.0..=1.0).contains(&weight1), "weight1 must be in the range [0, 1]");
+
+        // random may return exactly 1, which would pick the second one with a weight of 1
+        if weight1 >= 1.0 || self.random() < weight1 {
+            return self.gaussian(mean1, sd1);
+        }
+
+        return self.gaussian(mean2, sd2);
+    }
+
+    /// Returns a pair of random numbers for the standard gaussian distribution whose correlation is
+    /// `rho`.
+    /// 
+    /// Both numbers of a pair are drawn at once, and the second one is mixed into the first one.
+    /// Panics if `rho` isn't in the range [-1, 1].
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (height, weight) = rng.gaussian_correlated(0.7);
+    /// ```
+    pub fn gaussian_correlated(&mut self, rho: f64) -> (f64, f64) {
+        assert!((-1.0..=1.0).contains(&rho), "rho must be in the range [-1, 1]");
+
+        let (z1, independent) = self.standard_gaussian_pair();
+        return (z1, rho * z1 + (1.0 - rho * rho).sqrt() * independent);
+    }
+
+    /// Returns a random integer for a given gaussian distribution, rounding the number to the
+    /// nearest integer.
+    /// 
+    /// Halfway values are rounded away from zero (like `f64::round`) instead of to the nearest
+    /// even integer, as a gaussian number practically never falls exactly halfway. Values outside
+    /// of the `i64` range saturate to its bounds.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let height: i64 = rng.gaussian_int(170.0, 10.0);
+    /// ```
+    pub fn gaussian_int(&mut self, mean: f64, std_dev: f64) -> i64 {
+        return self.gaussian(mean, std_dev).round() as i64;
+    }
+
+    /// Returns a random integer for a given gaussian distribution truncated to `[low, high]`.
+    /// 
+    /// Numbers are rounded like in `gaussian_int`, and bounded like in `gaussian_clamped`: values
+    /// outside of the bounds are drawn again, and if none of a maximum of 100 draws falls inside
+    /// of them, the last one is clamped to the nearest bound.
+    /// 
+    /// Panics if `low` is bigger than `high`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let strength: i64 = rng.bounded_gaussian_int(10.0, 3.0, 3, 18);
+    /// ```
+    pub fn bounded_gaussian_int(&mut self, mean: f64, std_dev: f64, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "low can't be bigger than high");
+
+        let mut value = self.gaussian_int(mean, std_dev);
+        let mut tries = 1;
+
+        while (value < low || value > high) && tries < 100 {
+            value = self.gaussian_int(mean, std_dev);
+            tries += 1;
+        }
+
+        return value.clamp(low, high);
+    }
+
+    /// Returns a vector of length `n` with random numbers for a given gaussian distribution.
+    /// 
+    /// The values are the same ones that `n` calls to `gaussian` would return.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let values: Vec<f64> = rng.gaussian_vec(100, 0.0, 1.0);
+    /// ```
+    pub fn gaussian_vec(&mut self, n: usize, mean: f64, std_dev: f64) -> Vec<f64> {
+        let mut values: Vec<f64> = Vec::with_capacity(n);
+
+        // both numbers of each pair are used thanks to the spare one
+        for _ in 0..n {
+            values.push(self.gaussian(mean, std_dev));
+        }
+
+        return values;
+    }
+
+    /// Returns a random number for a given gaussian distribution truncated to `[low, high]`.
+    /// 
+    /// Values outside of the bounds are discarded and drawn again, and if none of a maximum of
+    /// 100 draws falls inside of the bounds, the last one is clamped to the nearest bound.
+    /// 
+    /// Panics if `low` is bigger than `high`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let age: f64 = rng.gaussian_clamped(35.0, 15.0, 0.0, 120.0);
+    /// ```
+    pub fn gaussian_clamped(&mut self, mean: f64, std_dev: f64, low: f64, high: f64) -> f64 {
+        assert!(low <= high, "low can't be bigger than high");
+
+        let mut value = self.gaussian(mean, std_dev);
+        let mut tries = 1;
+
+        while (value < low || value > high) && tries < 100 {
+            value = self.gaussian(mean, std_dev);
+            tries += 1;
+        }
+
+        return value.clamp(low, high);
+    }
+
+    /// Returns the first of a maximum of `max_tries` uniform random numbers in `[lower, upper]`
+    /// accepted by the given function, or `None` if none of them was accepted.
+    /// 
+    /// It can be used to draw numbers from any distribution, by accepting each number with a
+    /// probability proportional to its density.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: Option<f64> = rng.reject_sample(0.0, 10.0, |x| x.fract() < 0.5, 100);
+    /// ```
+    pub fn reject_sample<F: Fn(f64) -> bool>(
+        &mut self, lower: f64, upper: f64, accept: F, max_tries: usize
+    ) -> Option<f64> {
+        for _ in 0..max_tries {
+            let value = self.range_f64(lower, upper);
+
+            if accept(value) {
+                return Some(value);
+            }
+        }
+
+        return None;
+    }
+
+    /// Returns a random number for a given Pareto distribution.
+    /// 
+    /// It receives the scale, which is also the minimum value that can be returned, and the shape
+    /// of the distribution. Both must be positive.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.pareto(1.0, 3.0);
+    /// ```
+    pub fn pareto(&mut self, scale: f64, shape: f64) -> f64 {
+        assert!(scale > 0.0, "scale must be positive");
+        assert!(shape > 0.0, "shape must be positive");
+
+        let mut value = self.random();
+
+        // a value of 1 would make us divide by zero
+        while value >= 1.0 {
+            value = self.random();
+        }
+
+        return scale / (1.0 - value).powf(1.0 / shape);
+    }
+
+    /// Returns a random number in the range [`low`, `high`] uniformly distributed in log space, so
+    /// every order of magnitude is equally likely.
+    /// 
+    /// Panics unless `0 < low <= high`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let latency_ms: f64 = rng.log_uniform(0.1, 1000.0);
+    /// ```
+    pub fn log_uniform(&mut self, low: f64, high: f64) -> f64 {
+        assert!(low > 0.0, "low must be positive");
+        assert!(low <= high, "low can't be bigger than high");
+
+        let value = (low.ln() + (high.ln() - low.ln()) * self.random()).exp();
+
+        // rounding may leave the value barely outside the range
+        return value.clamp(low, high);
+    }
+
+    /// Returns a random number for a given negative binomial distribution, the number of failures
+    /// before the `r`-th success of trials with a success probability of `p`.
+    /// 
+    /// It adds up `r` draws of a geometric distribution. Panics if `r` is zero or `p` isn't in the
+    /// range (0, 1].
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let failures: u64 = rng.negative_binomial(3, 0.25);
+    /// ```
+    pub fn negative_binomial(&mut self, r: u64, p: f64) -> u64 {
+        assert!(r >= 1, "r must be at least 1");
+        assert!(p > 0.0 && p <= 1.0, "p must be in the range (0, 1]");
+
+        if p == 1.0 {
+            return 0;
+        }
+
+        let mut failures = 0;
+
+        for _ in 0..r {
+            let mut value = self.random();
+
+            // a value of 0 would make the logarithm infinite
+            while value <= 0.0 {
+                value = self.random();
+            }
+
+            failures += (value.ln() / (1.0 - p).ln()).floor() as u64;
+        }
+
+        return failures;
+    }
+
+    /// Returns a random rank in the range [1, n] for a given Zipf distribution, where the chance
+    /// of each rank is proportional to `1 / rank^exponent`.
+    /// 
+    /// Panics if `n` is zero or `exponent` isn't positive.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let word: u64 = rng.zipf(50_000, 1.07);
+    /// ```
+    pub fn zipf(&mut self, n: u64, exponent: f64) -> u64 {
+        // Rejection-inversion method by Hörmann and Derflinger
+        // https://dl.acm.org/doi/10.1145/235025.235029
+        assert!(n >= 1, "n must be at least 1");
+        assert!(exponent > 0.0, "exponent must be positive");
+
+        // the functions are written to stay accurate when the exponent is close to 1
+        let h = |x: f64| (-exponent * x.ln()).exp();
+        let h_integral = |x: f64| {
+            let log_x = x.ln();
+            let t = (1.0 - exponent) * log_x;
+            let ratio = if t.abs() > 1e-8 { t.exp_m1() / t } else { 1.0 + t * 0.5 };
+            return ratio * log_x;
+        };
+        let h_integral_inverse = |x: f64| {
+            let t = (x * (1.0 - exponent)).max(-1.0);
+            let ratio = if t.abs() > 1e-8 { t.ln_1p() / t } else { 1.0 - t * 0.5 };
+            return (ratio * x).exp();
+        };
+
+        let lower = h_integral(1.5) - 1.0;
+        let upper = h_integral(n as f64 + 0.5);
+        let squeeze = 2.0 - h_integral_inverse(h_integral(2.5) - h(2.0));
+
+        loop {
+            let u = upper + self.random() * (lower - upper);
+            let x = h_integral_inverse(u);
+            let k = (x + 0.5).clamp(1.0, n as f64).floor();
+
+            if k - x <= squeeze || u >= h_integral(k + 0.5) - h(k) {
+                return k as u64;
+            }
+        }
+    }
+
+    /// Returns a `Result` containing a random number for a given hypergeometric distribution, the
+    /// number of successes in `draws` draws without replacement from a population of `population`
+    /// elements, `successes` of which count as a success.
+    /// 
+    /// Neither `successes` nor `draws` can be bigger than the population.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// // aces in a hand of 5 cards
+    /// let aces: u64 = rng.hypergeometric(52, 4, 5).unwrap();
+    /// ```
+    pub fn hypergeometric(
+        &mut self, population: u64, successes: u64, draws: u64
+    ) -> Result<u64, &'static str> {
+        if successes > population {
+            return Err("successes can't be bigger than the population");
+        }
+
+        if draws > population {
+            return Err("draws can't be bigger than the population");
+        }
+
+        let mut left = population;
+        let mut left_successes = successes;
+
+        for _ in 0..draws {
+            let chance = left_successes as f64 / left as f64;
+
+            // random may return exactly 1, which is never smaller than the chance
+            if chance >= 1.0 || self.random() < chance {
+                left_successes -= 1;
+            }
+
+            left -= 1;
+        }
+
+        return Ok(successes - left_successes);
+    }
+
+    /// Returns a `Result` containing a random probability vector for a given Dirichlet
+    /// distribution, with one probability for each of the given concentration parameters.
+    /// 
+    /// The probabilities add up to 1. The parameters can't be empty and must be positive.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let probabilities: Vec<f64> = rng.dirichlet(&[1.0, 2.0, 3.0]).unwrap();
+    /// ```
+    pub fn dirichlet(&mut self, alpha: &[f64]) -> Result<Vec<f64>, &'static str> {
+        if alpha.is_empty() {
+            return Err("there must be at least one concentration parameter");
+        }
+
+        if alpha.iter().any(|value| *value <= 0.0) {
+            return Err("concentration parameters must be positive");
+        }
+
+        let mut values: Vec<f64> = alpha.iter().map(|value| self.standard_gamma(*value)).collect();
+        let total: f64 = values.iter().sum();
+
+        for value in values.iter_mut() {
+            *value /= total;
+        }
+
+        return Ok(values);
+    }
+
+    /// Returns a random number for a chi-squared distribution with the given degrees of freedom,
+    /// which must be positive.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.chi_square(3.0);
+    /// ```
+    pub fn chi_square(&mut self, df: f64) -> f64 {
+        assert!(df > 0.0, "df must be positive");
+
+        // the same as a gamma distribution of shape df / 2 and scale 2
+        return 2.0 * self.standard_gamma(df / 2.0);
+    }
+
+    /// Returns a random number for a Student's t distribution with the given degrees of freedom,
+    /// which must be positive.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.student_t(5.0);
+    /// ```
+    pub fn student_t(&mut self, df: f64) -> f64 {
+        assert!(df > 0.0, "df must be positive");
+
+        let z = self.gaussian(0.0, 1.0);
+
+        return z / (self.chi_square(df) / df).sqrt();
+    }
+
+    /// Returns a random number for an F distribution with the given degrees of freedom, which must
+    /// be positive.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let value: f64 = rng.f_distribution(5.0, 10.0);
+    /// ```
+    pub fn f_distribution(&mut self, d1: f64, d2: f64) -> f64 {
+        assert!(d1 > 0.0, "d1 must be positive");
+        assert!(d2 > 0.0, "d2 must be positive");
+
+        let numerator = self.chi_square(d1) / d1;
+
+        return numerator / (self.chi_square(d2) / d2);
+    }
+
+    /// Returns a random point uniformly distributed inside of a disk of the given radius, centered
+    /// at the origin.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (x, y): (f64, f64) = rng.point_in_disk(5.0);
+    /// ```
+    pub fn point_in_disk(&mut self, radius: f64) -> (f64, f64) {
+        // the square root keeps the points from clustering at the center
+        let r = radius * self.random().sqrt();
+        let theta = 2.0 * std::f64::consts::PI * self.random();
+
+        return (r * theta.cos(), r * theta.sin());
+    }
+
+    /// Returns a random point uniformly distributed inside of the triangle with the given corners.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (x, y): (f64, f64) = rng.point_in_triangle((0.0, 0.0), (4.0, 0.0), (0.0, 3.0));
+    /// ```
+    pub fn point_in_triangle(
+        &mut self, a: (f64, f64), b: (f64, f64), c: (f64, f64)
+    ) -> (f64, f64) {
+        // the square root keeps the points from clustering at the first corner
+        let r1 = self.random().sqrt();
+        let r2 = self.random();
+
+        // barycentric weights of each corner
+        let (wa, wb, wc) = (1.0 - r1, r1 * (1.0 - r2), r1 * r2);
+
+        return (wa * a.0 + wb * b.0 + wc * c.0, wa * a.1 + wb * b.1 + wc * c.1);
+    }
+
+    /// Returns a random unit vector, uniformly distributed on the unit circle.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (x, y): (f64, f64) = rng.unit_vector_2d();
+    /// ```
+    pub fn unit_vector_2d(&mut self) -> (f64, f64) {
+        let theta = 2.0 * std::f64::consts::PI * self.random();
+        return (theta.cos(), theta.sin());
+    }
+
+    /// Returns a random unit vector, uniformly distributed on the unit sphere.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (x, y, z): (f64, f64, f64) = rng.unit_vector_3d();
+    /// ```
+    pub fn unit_vector_3d(&mut self) -> (f64, f64, f64) {
+        // a uniform height keeps the points from clustering at the poles
+        // https://mathworld.wolfram.com/SpherePointPicking.html
+        let z = 2.0 * self.random() - 1.0;
+        let theta = 2.0 * std::f64::consts::PI * self.random();
+        let r = (1.0 - z * z).sqrt();
+
+        return (r * theta.cos(), r * theta.sin(), z);
+    }
+
+    /// Returns the result of rolling a die with `sides` faces, and whether it was the lowest and
+    /// the highest face, as `(value, is_min, is_max)`.
+    /// 
+    /// The value is the same one that `roll_pool(1, sides)` would return. Panics if `sides` is
+    /// zero.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (value, fumble, critical) = rng.roll_crit(20);
+    /// ```
+    pub fn roll_crit(&mut self, sides: u32) -> (u32, bool, bool) {
+        let value = self.roll_pool(1, sides)[0];
+        return (value, value == 1, value == sides);
+    }
+
+    /// Returns the individual results of rolling a pool of `count` dice with `sides` faces each.
+    /// 
+    /// Every face of a die is equally likely. Panics if `sides` is zero.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let pool: Vec<u32> = rng.roll_pool(5, 10);
+    /// let successes = pool.iter().filter(|die| **die >= 8).count();
+    /// ```
+    pub fn roll_pool(&mut self, count: usize, sides: u32) -> Vec<u32> {
+        assert!(sides > 0, "dice need at least one side");
+
+        let mut pool: Vec<u32> = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            pool.push(self.wide_index(sides as usize) as u32 + 1);
+        }
+
+        return pool;
+    }
+
+    /// Returns a `u8` vector of length `amount` with random values.
+    /// 
+    /// Every random byte of a generated number is used, like in `byte_stream`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let bytes: Vec<u8> = rng.randbytes(10);
+    /// ```
+    /// 
+    /// Warning: do not use this function for secure random bytes generation.
+    pub fn randbytes(&mut self, amount: usize) -> Vec<u8> {
+        return self.byte_stream().take(amount).collect();
+    }
+
+    /// Returns a `String` of `length` random printable ASCII characters, the 95 ones from the space
+    /// (`0x20`) to the tilde (`0x7E`).
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let text: String = rng.rand_printable(32);
+    /// ```
+    pub fn rand_printable(&mut self, length: usize) -> String {
+        let mut text = String::with_capacity(length);
+
+        for _ in 0..length {
+            text.push((b' ' + self.index(95).unwrap() as u8) as char);
+        }
+
+        return text;
+    }
+
+    /// Returns a random color as a `(r, g, b)` tuple.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let (r, g, b) = rng.rand_color();
+    /// ```
+    pub fn rand_color(&mut self) -> (u8, u8, u8) {
+        let [r, g, b] = self.randrange_n(T::Number::zero()..=T::Number::byte_max());
+
+        return (r.to_u8(), g.to_u8(), b.to_u8());
+    }
+
+    /// Returns a random color as a `#rrggbb` string.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let color: String = rng.rand_color_hex();
+    /// ```
+    pub fn rand_color_hex(&mut self) -> String {
+        let (r, g, b) = self.rand_color();
+
+        return format!("#{r:02x}{g:02x}{b:02x}");
+    }
+
+    /// Returns a random IPv4 address as its four octets.
+    /// 
+    /// Any address can be returned, including reserved ones like `127.0.0.1`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let [a, b, c, d] = rng.rand_ipv4();
+    /// ```
+    pub fn rand_ipv4(&mut self) -> [u8; 4] {
+        let octets: [T::Number; 4] = self.randrange_n(T::Number::zero()..=T::Number::byte_max());
+
+        return octets.map(|octet| octet.to_u8());
+    }
+
+    /// Returns a random IPv4 address in its dotted-quad form, e.g. `"192.168.4.20"`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let address: String = rng.rand_ipv4_string();
+    /// ```
+    pub fn rand_ipv4_string(&mut self) -> String {
+        let [a, b, c, d] = self.rand_ipv4();
+
+        return format!("{a}.{b}.{c}.{d}");
+    }
+
+    /// Returns a random identifier in the format of a version 4 UUID, e.g.
+    /// `"3f2b8c1e-9d4a-4e7b-a1c3-5f6d7e8f9a0b"`, with the version and variant bits set.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let id: String = rng.rand_uuid();
+    /// ```
+    /// 
+    /// Warning: the identifiers are predictable, do not use them as security tokens or anywhere
+    /// they need to be secret.
+    pub fn rand_uuid(&mut self) -> String {
+        let mut bytes = self.randbytes(16);
+        bytes[6] = (bytes[6] & 0x0F) | 0x40; // version 4
+        bytes[8] = (bytes[8] & 0x3F) | 0x80; // RFC 4122 variant
+
+        let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+
+        return format!(
+            "{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32]
+        );
+    }
+
+    /// Returns an array of `N` random bytes, without allocating.
+    /// 
+    /// The bytes are the same ones that `randbytes(N)` would return.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let key: [u8; 16] = rng.rand_array();
+    /// ```
+    /// 
+    /// Warning: do not use this function for secure random bytes generation.
+    pub fn rand_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = self.byte_stream();
+        return std::array::from_fn(|_| bytes.next().unwrap());
+    }
+
+    /// Returns a random byte that differs from the one returned by the previous call in exactly one
+    /// random bit, starting from zero, like a Gray code walked in a random order.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let first: u8 = rng.rand_graycode_byte();
+    /// let second: u8 = rng.rand_graycode_byte();
+    /// assert_eq!((first ^ second).count_ones(), 1);
+    /// ```
+    pub fn rand_graycode_byte(&mut self) -> u8 {
+        self.gray_byte ^= 1 << self.index(8).unwrap();
+        return self.gray_byte;
+    }
+
+    /// Returns an endless iterator of random bytes.
+    /// 
+    /// Every random byte of a generated number is used before generating the next one, so it needs
+    /// fewer calls to the algorithm than `randbytes`. Bytes left over from the last number are
+    /// discarded when the iterator is dropped.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let bytes: Vec<u8> = rng.byte_stream().take(1024).collect();
+    /// ```
+    /// 
+    /// Warning: do not use this function for secure random bytes generation.
+    pub fn byte_stream(&mut self) -> impl Iterator<Item = u8> + '_ {
+        let bytes_per_number = (T::raw_bits() / 8).max(1);
+        let mut number = T::Number::zero();
+        let mut left = 0;
+
+        return std::iter::from_fn(move || {
+            if left == 0 {
+                let value = self.next_raw();
+                number = self.audit(value);
+                left = bytes_per_number;
+            }
+
+            // Only the lowest byte is kept, the rest is returned by the next calls
+            let byte = number.to_u8();
+            number = number >> 8;
+            left -= 1;
+
+            return Some(byte);
+        });
+    }
+
+    /// Returns a `bool` vector of length `n` with random values.
+    /// 
+    /// Each random bit of a generated number is used as a value, starting from the least
+    /// significant one, before generating the next number.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let mask: Vec<bool> = rng.rand_bits(64);
+    /// ```
+    pub fn rand_bits(&mut self, n: usize) -> Vec<bool> {
+        let mut bits: Vec<bool> = Vec::with_capacity(n);
+        let mut number = T::Number::zero();
+        let mut left = 0;
+
+        while bits.len() < n {
+            if left == 0 {
+                let value = self.next_raw();
+                number = self.audit(value);
+                left = T::raw_bits();
+            }
+
+            bits.push(number.to_u8() & 1 == 1);
+            number = number >> 1;
+            left -= 1;
+        }
+
+        return bits;
+    }
+
+    /// Returns the next state of a two-state Markov chain, staying at `previous` with a probability
+    /// of `stay_prob` and flipping to the other state otherwise.
+    /// 
+    /// Panics if `stay_prob` isn't in the range [0, 1].
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let mut raining = false;
+    /// 
+    /// for _ in 0..30 {
+    ///     raining = rng.sticky_flip(raining, 0.8);
+    /// }
+    /// ```
+    pub fn sticky_flip(&mut self, previous: bool, stay_prob: f64) -> bool {
+        assert!((0.0..=1.0).contains(&stay_prob), "stay_prob must be in the range [0, 1]");
+
+        // random may return exactly 1, which would flip even with a stay_prob of 1
+        if stay_prob >= 1.0 || self.random() < stay_prob {
+            return previous;
+        }
+
+        return !previous;
+    }
+
+    /// Returns `true` with a probability of 1 in `n`, without any bias.
+    /// 
+    /// Panics if `n` is zero or bigger than the amount of numbers the algorithm can generate.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let critical: bool = rng.one_in(20);
+    /// ```
+    pub fn one_in(&mut self, n: u64) -> bool {
+        assert!(n >= 1, "n must be at least 1");
+        let len = usize::try_from(n).expect("n is bigger than the generator's range");
+
+        return self.index(len).unwrap() == 0;
+    }
+
+    /// Returns a `bool` vector of length `len` where each value is independently `true` with a
+    /// probability of `keep_prob`, unlike `sample` which always keeps the same amount.
+    /// 
+    /// Panics if `keep_prob` isn't in the range [0, 1].
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let mask: Vec<bool> = rng.dropout_mask(128, 0.9);
+    /// ```
+    pub fn dropout_mask(&mut self, len: usize, keep_prob: f64) -> Vec<bool> {
+        assert!((0.0..=1.0).contains(&keep_prob), "keep_prob must be in the range [0, 1]");
+
+        // random may return exactly 1, which would drop values even with a keep_prob of 1
+        return (0..len).map(|_| keep_prob >= 1.0 || self.random() < keep_prob).collect();
+    }
+
+    /// Returns the positions of a random walk starting at 0 after each of the given amount of
+    /// steps, where each step moves `step_size` up or down with the same probability.
+    /// 
+    /// The directions are taken from `rand_bits`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let prices: Vec<f64> = rng.random_walk(100, 0.5);
+    /// ```
+    pub fn random_walk(&mut self, steps: usize, step_size: f64) -> Vec<f64> {
+        let mut position = 0.0;
+
+        return self.rand_bits(steps).into_iter().map(|up| {
+            position += if up { step_size } else { -step_size };
+            return position;
+        }).collect();
+    }
+
+    /// Returns a `Result` containing a random index chosen according to the given probabilities.
+    /// 
+    /// The probabilities must be finite, can't be negative and must add up to 1, with a tolerance
+    /// of `1e-6`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let index: usize = rng.categorical(&[0.5, 0.25, 0.25]).unwrap();
+    /// ```
+    pub fn categorical(&mut self, probabilities: &[f64]) -> Result<usize, &'static str> {
+        if probabilities.iter().any(|probability| !probability.is_finite() || *probability < 0.0) {
+            return Err("probabilities must be finite and can't be negative");
+        }
+
+        let total: f64 = probabilities.iter().sum();
+
+        if (total - 1.0).abs() > 1e-6 {
+            return Err("probabilities must add up to 1");
+        }
+
+        let value = self.random() * total;
+        let mut cumulative = 0.0;
+
+        for (index, probability) in probabilities.iter().enumerate() {
+            cumulative += probability;
+
+            if value < cumulative {
+                return Ok(index);
+            }
+        }
+
+        // rounding errors may leave the value past the last cumulative probability
+        return Ok(probabilities.iter().rposition(|probability| *probability > 0.0).unwrap());
+    }
+
+    /// Returns a `Result` containing a random number following the cumulative distribution given
+    /// as a table of `(value, cumulative probability)` pairs, sorted by value.
+    /// 
+    /// The number is interpolated linearly between the two points around a random probability, and
+    /// the probability of the first point is the chance of returning exactly its value. The
+    /// cumulative probabilities can't decrease and must end at 1, with a tolerance of `1e-6`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let cdf = [(0.0, 0.0), (10.0, 0.8), (100.0, 1.0)];
+    /// let value: f64 = rng.from_cdf(&cdf).unwrap();
+    /// ```
+    pub fn from_cdf(&mut self, cdf: &[(f64, f64)]) -> Result<f64, &'static str> {
+        if cdf.is_empty() {
+            return Err("can't get a random number from an empty table");
+        }
+
+        if cdf.iter().any(|(_, probability)| *probability < 0.0) {
+            return Err("probabilities can't be negative");
+        }
+
+        if cdf.windows(2).any(|pair| pair[1].0 < pair[0].0 || pair[1].1 < pair[0].1) {
+            return Err("values and probabilities can't decrease");
+        }
+
+        let (last, total) = cdf[cdf.len() - 1];
+
+        if (total - 1.0).abs() > 1e-6 {
+            return Err("probabilities must end at 1");
+        }
+
+        let value = self.random();
+
+        if value < cdf[0].1 {
+            return Ok(cdf[0].0);
+        }
+
+        for pair in cdf.windows(2) {
+            let ((low, low_probability), (high, high_probability)) = (pair[0], pair[1]);
+
+            if value < high_probability {
+                let fraction = (value - low_probability) / (high_probability - low_probability);
+                return Ok(low + fraction * (high - low));
+            }
+        }
+
+        // rounding errors may leave the value past the last cumulative probability
+        return Ok(last);
+    }
+
+    /// Chooses a random value from a given vector or slice and returns a reference to it.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let list: Vec<String> = vec!["Hello".to_string(), "World".to_string()];
+    /// let value: &String = rng.choose(&list);
+    /// ```
+    pub fn choose<'a, G>(&mut self, vector: &'a [G]) -> &'a G {
+        let end: T::Number = T::Number::from_usize(vector.len());
+        let index = self.randrange(T::Number::zero()..end).to_usize();
+        return &vector[index];
+    }
+
+    /// Chooses a random value from a given slice and returns a copy of it, or `None` if the slice
+    /// is empty.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// #[derive(Clone, Copy)]
+    /// enum Direction { North, South, East, West }
+    /// 
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let directions = [Direction::North, Direction::South, Direction::East, Direction::West];
+    /// let direction: Option<Direction> = rng.choose_owned(&directions);
+    /// ```
+    pub fn choose_owned<G: Copy>(&mut self, items: &[G]) -> Option<G> {
+        return self.index(items.len()).map(|index| items[index]);
+    }
+
+    /// Chooses a random value from a given slice of allowed values, or `None` if the slice is
+    /// empty. Works like `choose_owned`, reading better when the slice is a set of values rather
+    /// than a collection of items.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let size: Option<u32> = rng.choose_value(&[2, 4, 8, 16]);
+    /// ```
+    pub fn choose_value<G: Copy>(&mut self, values: &[G]) -> Option<G> {
+        return self.choose_owned(values);
+    }
+
+    /// Returns a vector of `size` values drawn with replacement from a given slice, so the same
+    /// value can appear more than once. Returns an empty vector if the slice is empty.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let data: Vec<f64> = vec![2.5, 3.0, 1.5, 4.0];
+    /// let resample: Vec<f64> = rng.bootstrap(&data, data.len());
+    /// ```
+    pub fn bootstrap<G: Clone>(&mut self, data: &[G], size: usize) -> Vec<G> {
+        if data.is_empty() {
+            return Vec::new();
+        }
+
+        return (0..size).map(|_| data[self.index(data.len()).unwrap()].clone()).collect();
+    }
+
+    /// Returns a `Result` containing a random contiguous window of length `window` from a given
+    /// slice.
+    /// 
+    /// The given `window` can't be bigger than the length of the slice.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let series: Vec<f64> = vec![1.0, 1.5, 2.5, 2.0, 3.0, 3.5];
+    /// let window: &[f64] = rng.random_window(&series, 3).unwrap();
+    /// ```
+    pub fn random_window<'a, G>(
+        &mut self, data: &'a [G], window: usize
+    ) -> Result<&'a [G], &'static str> {
+        if window > data.len() {
+            return Err("can't get a window bigger than the data");
+        }
+
+        let start = self.index(data.len() - window + 1).unwrap();
+        return Ok(&data[start..start + window]);
+    }
+
+    // The Fisher-Yates shuffle as described in
+    // https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle
+    /// Performs an inplace Fisher-Yates shuffle on the contents of a collection.
+    /// 
+    /// Works with any collection implementing `Shuffleable`, like a `Vec`, a slice or a
+    /// `VecDeque`.
+    /// 
+    /// The swaps made only depend on the state of the generator and the length of the collection,
+    /// never on its type or contents, so the same seed always moves the elements of collections of
+    /// the same length in the same way.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let list: Vec<String> = vec!["Hello".to_string(), "World".to_string()];
+    /// rng.shuffle(&list);
+    /// ```
+    pub fn shuffle<S: Shuffleable + ?Sized>(&mut self, collection: &mut S) {
+        let mut items = collection.len();
+
+        while items > 1 {
+            items -= 1;
+
+            let pos = self.randrange(
+                T::Number::zero()..=T::Number::from_usize(items)
+            ).to_usize();
+
+            if pos != items {
+                collection.swap(pos, items);
+            }
+        }
+    }
+
+    /// Performs the same inplace Fisher-Yates shuffle on two slices, so that elements at the same
+    /// position in both stay together.
+    /// 
+    /// The elements are moved in the same way `shuffle` would move them. Returns an error if the
+    /// slices have different lengths.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let mut features = vec![[0.5, 1.0], [0.25, 2.0], [0.75, 3.0]];
+    /// let mut labels = vec![true, false, true];
+    /// rng.shuffle_together(&mut features, &mut labels).unwrap();
+    /// ```
+    pub fn shuffle_together<A, B>(&mut self, a: &mut [A], b: &mut [B]) -> Result<(), &'static str> {
+        if a.len() != b.len() {
+            return Err("can't shuffle slices of different lengths together");
+        }
+
+        let mut items = a.len();
+
+        while items > 1 {
+            items -= 1;
+
+            let pos = self.randrange(
+                T::Number::zero()..=T::Number::from_usize(items)
+            ).to_usize();
+
+            if pos != items {
+                a.swap(pos, items);
+                b.swap(pos, items);
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Returns a random permutation of the numbers in the range [0, n).
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let order: Vec<usize> = rng.permutation(10);
+    /// ```
+    pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut values: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut values);
+        return values;
+    }
+
+    /// Returns a `Result` containing the contents of a given slice split into `groups` random
+    /// groups.
+    /// 
+    /// The sizes of the groups differ by one at most, with the first groups being the bigger ones.
+    /// The amount of groups can't be zero.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let players = ["Ana", "Bruno", "Carla", "Diego", "Elena"];
+    /// let teams: Vec<Vec<&str>> = rng.partition(&players, 2).unwrap();
+    /// ```
+    pub fn partition<G: Clone>(
+        &mut self, data: &[G], groups: usize
+    ) -> Result<Vec<Vec<G>>, &'static str> {
+        if groups == 0 {
+            return Err("the amount of groups can't be zero");
+        }
+
+        let mut items: Vec<G> = data.to_vec();
+        self.shuffle(&mut items);
+
+        let size = items.len() / groups;
+        let bigger = items.len() % groups;
+        let mut items = items.into_iter();
+
+        return Ok((0..groups).map(|group| {
+            let length = if group < bigger { size + 1 } else { size };
+            return items.by_ref().take(length).collect();
+        }).collect());
+    }
+
+    /// Returns a `Result` containing a random sample of length `amount` from the contents of a
+    /// given vector.
+    /// 
+    /// The given `amount` can't be bigger than the total population.
+    /// 
+    /// The positions of the elements are chosen with `distinct_indices`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let list: Vec<String> = vec!["Hello".to_string(), "World".to_string()];
+    /// let sample: Result<Vec<&String>, &'static str> = rng.sample(&list, 1);
+    /// ```
+    pub fn sample<'a, G>(
+        &'a mut self, vector: &'a Vec<G>, amount: usize
+    ) -> Result<Vec<&G>, &'static str> {
+        let positions = self.distinct_indices(vector.len(), amount)?;
+        return Ok(positions.into_iter().map(|pos| &vector[pos]).collect());
+    }
+
+    /// Returns a `Result` containing `k` distinct random indices in the range [0, range_len), in
+    /// the order they were drawn.
+    /// 
+    /// The given `k` can't be bigger than `range_len`. Random indices are drawn until enough
+    /// distinct ones are found, checking the ones found so far with a linear scan when `k` is at
+    /// most 8, or with a `HashSet` otherwise. When `k` is more than half of `range_len`, a partial
+    /// Fisher-Yates shuffle of the indices is used instead, as most draws would be repeated ones.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let indices: Vec<usize> = rng.distinct_indices(1_000_000, 3).unwrap();
+    /// ```
+    pub fn distinct_indices(
+        &mut self, range_len: usize, k: usize
+    ) -> Result<Vec<usize>, &'static str> {
+        if k > range_len {
+            return Err("can't get a sample bigger than the population");
+        }
+
+        if k > range_len / 2 {
+            let mut positions: Vec<usize> = (0..range_len).collect();
+
+            // only the first `k` positions need to be shuffled
+            for i in 0..k {
+                let pos = i + self.index(range_len - i).unwrap();
+                positions.swap(i, pos);
+            }
+
+            positions.truncate(k);
+            return Ok(positions);
+        }
+
+        let end = T::Number::from_usize(range_len);
+        let mut selected: Vec<usize> = Vec::with_capacity(k);
+
+        if k <= 8 {
+            while selected.len() < k {
+                let pos = self.randrange(T::Number::zero()..end).to_usize();
+
+                if !selected.contains(&pos) {
+                    selected.push(pos);
+                }
+            }
+
+            return Ok(selected);
+        }
+
+        let mut positions: HashSet<usize> = HashSet::with_capacity(k);
+
+        while selected.len() < k {
+            let pos = self.randrange(T::Number::zero()..end).to_usize();
+
+            if positions.insert(pos) {
+                selected.push(pos);
+            }
+        }
+
+        return Ok(selected);
+    }
+
+    /// Returns a `Result` containing a random sample of length `amount` from the contents of a
+    /// given slice, keeping the order the elements have in it.
+    /// 
+    /// The given `amount` can't be bigger than the total population.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let lines = vec!["first", "second", "third", "fourth"];
+    /// let sample: Vec<&&str> = rng.sample_ordered(&lines, 2).unwrap();
+    /// ```
+    pub fn sample_ordered<'a, G>(
+        &mut self, vector: &'a [G], amount: usize
+    ) -> Result<Vec<&'a G>, &'static str> {
+        let length = vector.len();
+
+        if amount > length {
+            return Err("can't get a sample bigger than the population");
+        }
+
+        let mut positions: HashSet<usize> = HashSet::with_capacity(amount);
+
+        while positions.len() < amount {
+            positions.insert(self.index(length).unwrap());
+        }
+
+        let mut positions: Vec<usize> = positions.into_iter().collect();
+        positions.sort_unstable();
+
+        return Ok(positions.into_iter().map(|pos| &vector[pos]).collect());
+    }
+
+    // Algorithm R as described in
+    // https://en.wikipedia.org/wiki/Reservoir_sampling#Simple:_Algorithm_R
+    /// Returns a random sample of length `k` from the items of an iterator, going through it only
+    /// once and without collecting it.
+    /// 
+    /// If the iterator has fewer than `k` items, all of them are returned.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let sample: Vec<u32> = rng.reservoir((0..1_000_000).filter(|x| x % 7 == 0), 10);
+    /// ```
+    pub fn reservoir<I: Iterator<Item = G>, G>(&mut self, iter: I, k: usize) -> Vec<G> {
+        let mut selected: Vec<G> = Vec::with_capacity(k);
+
+        for (seen, item) in iter.enumerate() {
+            if seen < k {
+                selected.push(item);
+                continue;
+            }
+
+            let pos = self.wide_index(seen + 1);
+
+            if pos < k {
+                selected[pos] = item;
+            }
+        }
+
+        return selected;
+    }
+
+    /// Returns a random index in the range [0, len), like `index`, but also for lengths over the
+    /// range of the raw numbers, which are covered with 64 bits numbers made by `raw_u64`.
+    fn wide_index(&mut self, len: usize) -> usize {
+        let raw_max = T::Number::max() >> (T::Number::bits() - T::raw_bits());
+
+        if len as u64 - 1 <= raw_max.to_u64() {
+            return self.index(len).unwrap_or(0);
+        }
+
+        let len = len as u64;
+        // the numbers over the last multiple of `len` would favour the lowest indices
+        let limit = u64::MAX - (u64::MAX % len + 1) % len;
+
+        loop {
+            let value = Random::raw_u64(&mut self.algorithm);
+            self.draws += u64::from(64_u32.div_ceil(T::raw_bits()));
+
+            if value <= limit {
+                return (value % len) as usize;
+            }
+        }
+    }
+
+    /// Returns a `u64` made from as many raw numbers of an algorithm as needed to fill it.
+    fn raw_u64(algorithm: &mut T) -> u64 {
+        let mut value: u64 = 0;
+        let mut bits = 0;
+
+        while bits < 64 {
+            value = value.rotate_left(T::raw_bits() % 64) ^ algorithm.next_raw().to_u64();
+            bits += T::raw_bits();
+        }
+
+        return value;
+    }
+
+    /// Creates a new `Random` struct around an algorithm, without any spare gaussian number.
+    fn from_algorithm(algorithm: T, seed: Option<T::Seed>, noise_key: u64) -> Random<T> {
+        return Random {
+            algorithm, seed, spare: None, gaussian_cache: true, gray_byte: 0, digest: None,
+            draws: 0, noise_key
+        };
+    }
+
+    /// Returns the 64 bits FNV-1a hash of some bytes.
+    fn fnv1a(bytes: &[u8]) -> u64 {
+        // https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+        let mut hash: u64 = 0xCBF29CE484222325;
+
+        for &byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001B3);
+        }
+
+        return hash;
+    }
+
+    /// Returns the key of `value_noise_2d` for a seed, the FNV-1a hash of its bytes.
+    fn seed_key(seed: &T::Seed) -> u64 {
+        let mut bytes: Vec<u8> = Vec::new();
+        seed.write_bytes(&mut bytes);
+
+        return Random::<T>::fnv1a(&bytes);
+    }
+
+    /// Returns the next raw number of the algorithm, counting the draw.
+    fn next_raw(&mut self) -> T::Number {
+        self.draws += 1;
+        return self.algorithm.next_raw();
+    }
+
+    /// Sets whether the generated values are mixed into a digest, starting a new one.
+    pub(crate) fn set_audited(&mut self, enabled: bool) {
+        // https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+        self.digest = if enabled { Some(0xCBF29CE484222325) } else { None };
+    }
+
+    /// Mixes a generated value into the digest of an audited generator and returns it.
+    fn audit(&mut self, value: T::Number) -> T::Number {
+        if let Some(digest) = self.digest {
+            let mut hash = (digest ^ value.to_u64()).wrapping_mul(0x100000001B3);
+
+            if T::Number::bits() > 64 {
+                hash = (hash ^ (value >> 64).to_u64()).wrapping_mul(0x100000001B3);
+            }
+
+            self.digest = Some(hash);
+        }
+
+        return value;
+    }
+
+    /// Sets whether the second number of each gaussian pair is kept for the next call.
+    pub(crate) fn set_gaussian_cache(&mut self, enabled: bool) {
+        self.gaussian_cache = enabled;
+        self.spare = None;
+    }
+
+    /// Returns a pair of independent numbers for the standard gaussian distribution.
+    fn standard_gaussian_pair(&mut self) -> (f64, f64) {
+        // Box-Muller transform
+        // https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform
+        let mut u1 = self.random();
+
+        // the logarithm of zero is undefined
+        while u1 <= 0.0 {
+            u1 = self.random();
+        }
+
+        let radius = (-2.0 * u1.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * self.random();
+
+        return (radius * theta.cos(), radius * theta.sin());
+    }
+
+    /// Returns a random number for the gamma distribution with the given shape and a scale of 1.
+    fn standard_gamma(&mut self, shape: f64) -> f64 {
+        // Marsaglia and Tsang's method
+        // https://dl.acm.org/doi/10.1145/358407.358414
+        if shape < 1.0 {
+            // boost the shape over 1 and scale the number back down
+            let mut u = self.random();
+
+            while u <= 0.0 {
+                u = self.random();
+            }
+
+            return self.standard_gamma(shape + 1.0) * u.powf(1.0 / shape);
+        }
+
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+
+        loop {
+            let x = self.gaussian(0.0, 1.0);
+            let v = (1.0 + c * x).powi(3);
+
+            if v <= 0.0 {
+                continue;
+            }
+
+            let u = self.random();
+
+            if u > 0.0 && u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                return d * v;
+            }
+        }
+    }
+}
+
+impl<T> Random<T>
+    where T: RandomAlgorithm + Clone
+{
+    /// Returns the random number in a given range that the next call to `randrange` would return,
+    /// without advancing the generator.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let next: u32 = rng.peek_range(1..=6);
+    /// assert_eq!(next, rng.randrange(1..=6));
+    /// ```
+    pub fn peek_range<R: ValidRandomRange<T::Number>>(&self, range: R) -> T::Number {
+        let mut algorithm = self.algorithm.clone();
+        return algorithm.randrange(range);
+    }
+
+    /// Creates a new `Random` struct for the worker or task with the given index, seeded with the
+    /// seed of the current one mixed with the index through SplitMix64.
+    /// 
+    /// It doesn't depend on the numbers generated so far, so the same index always gives the same
+    /// generator, and different indices give unrelated ones.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let rng: Random<MersenneTwister> = Random::seed(42).unwrap();
+    /// let mut worker: Random<MersenneTwister> = rng.stream_for(3);
+    /// ```
+    pub fn stream_for(&self, index: u64) -> Random<T> {
+        // the noise key is taken from the seed, and is kept by generators without a known one
+        let mut splitmix = SplitMix64::new(self.noise_key ^ SplitMix64::new(index).next());
+
+        loop {
+            // the mixed seed may still be an invalid one for the algorithm
+            let value = splitmix.next();
+
+            if let Ok(algorithm) = T::from_u64(value) {
+                return Random::from_algorithm(algorithm, None, SplitMix64::new(value).next());
+            }
+        }
+    }
+
+    /// Returns the element at `position` of a random permutation of the numbers in the range
+    /// [0, n), without building the whole permutation.
+    /// 
+    /// The permutation is chosen from its Lehmer code, whose digits are drawn from a copy of the
+    /// generator, so like `peek_range` it doesn't advance the generator and every call with the
+    /// same `n` refers to the same permutation until the generator is used again. Only the digits
+    /// up to `position` are drawn.
+    /// 
+    /// Every digit up to `position` has to be decoded on each call, which takes O(position²) time,
+    /// so it's meant for the first positions of huge permutations. Use `permutation` to get every
+    /// position of a small one.
+    /// 
+    /// Panics if `position` isn't smaller than `n`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let rng: Random<MersenneTwister> = Random::new();
+    /// let first: usize = rng.nth_permutation_element(1_000_000_000, 0);
+    /// let second: usize = rng.nth_permutation_element(1_000_000_000, 1);
+    /// assert_ne!(first, second);
+    /// ```
+    pub fn nth_permutation_element(&self, n: usize, position: usize) -> usize {
+        assert!(position < n, "position must be smaller than n");
+
+        let mut copy = Random::from_algorithm(self.algorithm.clone(), None, self.noise_key);
+        // elements taken by the previous positions, in ascending order
+        let mut taken: Vec<usize> = Vec::with_capacity(position);
+        let mut element = 0;
+
+        for i in 0..=position {
+            // the digit is the rank of the element among the ones not taken yet
+            element = copy.index(n - i).unwrap();
+
+            for &other in &taken {
+                if other > element {
+                    break;
+                }
+
+                element += 1;
+            }
+
+            let at = taken.partition_point(|&other| other < element);
+            taken.insert(at, element);
+        }
+
+        return element;
+    }
+}
+
+impl<T> Random<T>
+    where T: StatefulAlgorithm
+{
+    /// Returns a copy of the full internal state, the one of the underlying algorithm along with
+    /// the spare gaussian number, if any, the last byte of `rand_graycode_byte` and the key of
+    /// `value_noise_2d`.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister, RandomState};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let state: RandomState<([u32; 624], u32)> = rng.state();
+    /// ```
+    pub fn state(&self) -> RandomState<T::State> {
+        return RandomState {
+            algorithm: self.algorithm.state(), spare: self.spare, gray_byte: self.gray_byte,
+            noise_key: self.noise_key
+        };
+    }
+
+    /// Creates a new `Random` struct with the given internal state, continuing the sequence of the
+    /// generator the state was taken from, gaussian numbers included.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let mut copy: Random<MersenneTwister> = Random::restore(rng.state());
+    /// assert_eq!(rng.randrange(1..=6), copy.randrange(1..=6));
+    /// ```
+    pub fn restore(state: RandomState<T::State>) -> Random<T> {
+        let mut rng = Random::from_algorithm(T::from_state(state.algorithm), None, state.noise_key);
+        rng.spare = state.spare;
+        rng.gray_byte = state.gray_byte;
+
+        return rng;
+    }
+
+    /// Returns the full internal state, as returned by `state`, encoded as a hexadecimal string,
+    /// which can be given to `from_hex` to continue the sequence.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, XORShift64};
+    /// let rng: Random<XORShift64> = Random::seed(42).unwrap();
+    /// assert_eq!(rng.to_hex(), "2a000000000000000000efda89376bdd3aff");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        let mut bytes: Vec<u8> = Vec::new();
+        self.state().write_bytes(&mut bytes);
+
+        return bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    }
+
+    /// Creates a new `Random` struct from an internal state encoded by `to_hex`.
+    /// 
+    /// Returns an error if the string isn't valid hexadecimal, doesn't have the exact length of a
+    /// state or the state is a degenerate one the algorithm can't use, like a zeroed xor shift.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, XORShift64};
+    /// let mut rng: Random<XORShift64> = Random::from_hex("2a000000000000000000efda89376bdd3aff")
+    ///     .unwrap();
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Random<T>, &'static str> {
+        if !s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err("the state must only have hexadecimal digits");
+        }
+
+        if s.len() % 2 != 0 {
+            return Err("the state must be an even amount of hexadecimal digits");
+        }
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(s.len() / 2);
+
+        for i in (0..s.len()).step_by(2) {
+            match u8::from_str_radix(&s[i..i + 2], 16) {
+                Ok(byte) => bytes.push(byte),
+                Err(_) => return Err("the state must only have hexadecimal digits")
+            }
+        }
+
+        return match RandomState::<T::State>::read_bytes(&bytes) {
+            Some((state, [])) => {
+                T::validate_state(&state.algorithm)?;
+                Ok(Random::restore(state))
+            },
+            _ => Err("the state doesn't have the length of a state of the algorithm")
+        };
+    }
+}
+
+impl Random<MersenneTwister> {
+    /// Creates a new `Random` struct with a Mersenne Twister seeded from an array of values, like
+    /// `init_by_array` of the reference implementation.
+    /// 
+    /// Returns an error if the key is empty. As the key isn't a single seed, `seed_used` returns
+    /// `None` for the generator.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister};
+    /// let mut rng: Random<MersenneTwister> = Random::seed_array(&[0x123, 0x234, 0x345, 0x456])
+    ///     .unwrap();
+    /// ```
+    pub fn seed_array(key: &[u32]) -> Result<Random<MersenneTwister>, &'static str> {
+        let algorithm = MersenneTwister::new_from_array(key)?;
+        let bytes: Vec<u8> = key.iter().flat_map(|value| value.to_le_bytes()).collect();
+        let noise_key = Random::<MersenneTwister>::fnv1a(&bytes);
+
+        return Ok(Random::from_algorithm(algorithm, None, noise_key));
+    }
+}
+
+impl<T> fmt::Debug for Random<T>
+    where T: RandomAlgorithm + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return f.debug_struct("Random")
+            .field("algorithm", &self.algorithm)
+            .field("seed", &self.seed)
+            .finish();
+    }
+}
+
+// The seed is left out, as generators in the same state give the same numbers no matter how they
+// were created.
+impl<T> PartialEq for Random<T>
+    where T: RandomAlgorithm + PartialEq
+{
+    fn eq(&self, other: &Random<T>) -> bool {
+        return self.algorithm == other.algorithm
+            && self.spare == other.spare
+            && self.gaussian_cache == other.gaussian_cache
+            && self.gray_byte == other.gray_byte
+            && self.noise_key == other.noise_key;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use crate::xorshift::{XORShift64, XORShift128Plus};
+
+    // Mersenne Twister that counts how many numbers it has generated.
+    struct Counting {
+        algorithm: MersenneTwister,
+        calls: usize
+    }
+
+    impl RandomAlgorithm for Counting {
+        type Seed = u32;
+        type Number = u32;
+
+        fn new(seed: Self::Seed) -> Result<Counting, &'static str> {
+            return Ok(Counting { algorithm: MersenneTwister::new(seed)?, calls: 0 });
+        }
+
+        fn default() -> Counting {
+            return Counting { algorithm: MersenneTwister::default(), calls: 0 };
+        }
+
+        fn next_raw(&mut self) -> u32 {
+            self.calls += 1;
+            return self.algorithm.next_raw();
+        }
+    }
+
+    // Mersenne Twister that generates signed numbers.
+    struct Signed {
+        algorithm: MersenneTwister
+    }
+
+    impl RandomAlgorithm for Signed {
+        type Seed = u32;
+        type Number = i32;
+
+        fn new(seed: Self::Seed) -> Result<Signed, &'static str> {
+            return Ok(Signed { algorithm: MersenneTwister::new(seed)? });
+        }
+
+        fn default() -> Signed {
+            return Signed { algorithm: MersenneTwister::default() };
+        }
+
+        fn next_raw(&mut self) -> i32 {
+            return self.algorithm.next_raw() as i32;
+        }
+    }
+
+    #[test]
+    fn from_u64() {
+        let mut rng: Random<MersenneTwister> = Random::from_u64(10).unwrap();
+        let mut same: Random<MersenneTwister> = Random::from_u64(10).unwrap();
+        let mut other: Random<MersenneTwister> = Random::from_u64(10 + (1 << 32)).unwrap();
+        let value = rng.randrange(..);
+        assert_eq!(value, same.randrange(..));
+        assert_ne!(value, other.randrange(..));
+    }
+
+    #[test]
+    fn seed_from_str() {
+        let mut rng: Random<MersenneTwister> = Random::seed_from_str("world").unwrap();
+        let mut same: Random<MersenneTwister> = Random::seed_from_str("world").unwrap();
+        let mut other: Random<MersenneTwister> = Random::seed_from_str("World").unwrap();
+        let value = rng.randrange(..);
+        assert_eq!(value, same.randrange(..));
+        assert_ne!(value, other.randrange(..));
+    }
+
+    #[test]
+    fn combine() {
+        let first: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let second: Random<MersenneTwister> = Random::seed(20).unwrap();
+        let mut rng = Random::combine(first, second);
+        let value = rng.randrange(..);
+        let first = MersenneTwister::new(10).unwrap().next_raw();
+        let second = MersenneTwister::new(20).unwrap().next_raw();
+        assert_ne!(value, first);
+        assert_ne!(value, second);
+        assert_eq!(value, first ^ second);
+        assert_eq!(rng.seed_used(), Some((10, 20)));
+    }
+
+    #[test]
+    fn into_stream() {
+        let rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values: Vec<u32> = rng.into_stream().take(5).collect();
+        let mut algorithm = MersenneTwister::new(10).unwrap();
+        let expected: Vec<u32> = (0..5).map(|_| algorithm.next_raw()).collect();
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn algorithm_metadata() {
+        assert_eq!(Random::<MersenneTwister>::algorithm_name(), "MersenneTwister");
+        assert_eq!(Random::<MersenneTwister>::period_log2(), 19937);
+        assert_eq!(Random::<XORShift64>::algorithm_name(), "XORShift64");
+        assert_eq!(Random::<XORShift64>::period_log2(), 64);
+        assert_eq!(Random::<Counting>::algorithm_name(), "Unknown");
+        assert_eq!(Random::<Counting>::period_log2(), 0);
+    }
+
+    #[test]
+    fn seed_used() {
+        let rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.seed_used(), Some(10));
+        let rng: Random<MersenneTwister> = Random::new();
+        assert_eq!(rng.seed_used(), None);
+    }
+
+    #[test]
+    fn debug() {
+        let rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let output = format!("{rng:?}");
+        assert!(output.contains("MersenneTwister"));
+        assert!(output.contains("Some(10)"));
+    }
+
+    #[test]
+    fn equal_states() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut same: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..1000 {
+            rng.randrange(..);
+            same.randrange(..);
+        }
+
+        assert!(rng == same);
+        rng.randrange(..);
+        assert!(rng != same);
+
+        // the same algorithm state with a different noise key gives different noise values
+        let mut state = rng.state();
+        state.noise_key ^= 1;
+        let other: Random<MersenneTwister> = Random::restore(state);
+        assert!(Random::<MersenneTwister>::restore(rng.state()) == rng);
+        assert!(other != rng);
+
+        let mut other: Random<MersenneTwister> = Random::restore(rng.state());
+        other.set_gaussian_cache(false);
+        assert!(other != rng);
+    }
+
+    #[test]
+    fn split() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut values: HashSet<u32> = HashSet::new();
+
+        for _ in 0..3 {
+            let mut child = rng.split();
+
+            for _ in 0..100 {
+                assert!(values.insert(child.randrange(..)));
+            }
+        }
+    }
+
+    #[test]
+    fn stream_for() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let first: Vec<u32> = rng.stream_for(0).into_stream().take(100).collect();
+        rng.randrange(..);
+        let again: Vec<u32> = rng.stream_for(0).into_stream().take(100).collect();
+        let second: Vec<u32> = rng.stream_for(1).into_stream().take(100).collect();
+        assert_eq!(first, again);
+        assert_ne!(first, second);
+        assert!(first.iter().all(|value| !second.contains(value)));
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..1000 {
+            rng.randrange(..);
+        }
+
+        let hex = rng.to_hex();
+        assert_eq!(hex.len(), (624 + 1) * 8 + 2 + 2 + 16);
+        let mut restored: Random<MersenneTwister> = Random::from_hex(&hex).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.randrange(..), rng.randrange(..));
+        }
+
+        assert!(Random::<MersenneTwister>::from_hex(&hex[2..]).is_err());
+        assert!(Random::<MersenneTwister>::from_hex("zz").is_err());
+    }
+
+    #[test]
+    fn hex_degenerate_state() {
+        assert!(Random::<XORShift64>::from_hex(&"00".repeat(18)).is_err());
+        assert!(Random::<XORShift128Plus>::from_hex(&"00".repeat(26)).is_err());
+        assert!(Random::<crate::minstd::MinStd>::from_hex(&"00".repeat(14)).is_err());
+        let hex = format!("ffffff7f0000{}", "00".repeat(8));
+        assert!(Random::<crate::minstd::MinStd>::from_hex(&hex).is_err());
+        assert!(Random::<crate::xoshiro::Xoshiro128PlusPlus>::from_hex(&"00".repeat(26)).is_err());
+        let hex = Random::<XORShift64>::seed(42).unwrap().to_hex();
+        assert!(Random::<XORShift64>::from_hex(&hex).is_ok());
+
+        let odd = Random::<XORShift64>::from_hex("2a0").unwrap_err();
+        let digit = Random::<XORShift64>::from_hex("2g").unwrap_err();
+        assert_ne!(odd, digit);
+    }
+
+    #[test]
+    fn nth_permutation_element() {
+        let rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values: Vec<usize> = (0..10).map(|p| rng.nth_permutation_element(10, p)).collect();
+        assert_eq!(values, vec![7, 3, 5, 1, 0, 2, 9, 6, 8, 4]);
+
+        let mut sorted = values.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..10).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn restore() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        // go through a twist of the state
+        for _ in 0..1000 {
+            rng.randrange(..);
+        }
+
+        let mut restored: Random<MersenneTwister> = Random::restore(rng.state());
+
+        for _ in 0..1000 {
+            assert_eq!(restored.randrange(..), rng.randrange(..));
+        }
+    }
+
+    #[test]
+    fn restore_gaussian() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        rng.gaussian(0.0, 1.0);
+
+        // the second number of the pair is kept in the state
+        let mut restored: Random<MersenneTwister> = Random::restore(rng.state());
+        let mut decoded: Random<MersenneTwister> = Random::from_hex(&rng.to_hex()).unwrap();
+        let value = rng.gaussian(0.0, 1.0);
+        assert_eq!(restored.gaussian(0.0, 1.0), value);
+        assert_eq!(decoded.gaussian(0.0, 1.0), value);
+        assert_eq!(restored.gaussian(0.0, 1.0), rng.gaussian(0.0, 1.0));
+    }
+
+    #[test]
+    fn randrange() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.randrange(0..10);
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn peek_range() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..100 {
+            let peeked = rng.peek_range(0..10);
+            assert_eq!(rng.peek_range(0..10), peeked);
+            assert_eq!(rng.randrange(0..10), peeked);
+        }
+    }
+
+    #[test]
+    fn digest() {
+        let mut rng: Random<MersenneTwister> = Random::builder().seed(10).audited(true).build()
+            .unwrap();
+        let mut same: Random<MersenneTwister> = Random::builder().seed(10).audited(true).build()
+            .unwrap();
+        let mut other: Random<MersenneTwister> = Random::builder().seed(11).audited(true).build()
+            .unwrap();
+
+        for rng in [&mut rng, &mut same, &mut other] {
+            rng.randrange(0..100);
+            rng.random();
+            rng.randbytes(5);
+            rng.shuffle(&mut vec![1, 2, 3, 4]);
+        }
+
+        assert_eq!(rng.digest(), same.digest());
+        assert_ne!(rng.digest(), other.digest());
+        assert_ne!(rng.digest(), 0);
+        assert_eq!(Random::<MersenneTwister>::seed(10).unwrap().digest(), 0);
+    }
+
+    #[test]
+    fn one_in() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values: Vec<bool> = (0..8).map(|_| rng.one_in(3)).collect();
+        assert_eq!(values, vec![false, true, true, false, true, false, false, false]);
+        assert!(rng.one_in(1));
+
+        let trues = (0..60000).filter(|_| rng.one_in(6)).count();
+        assert!((9000..11000).contains(&trues));
+    }
+
+    #[test]
+    fn dropout_mask() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mask = rng.dropout_mask(100000, 0.3);
+        let kept = mask.iter().filter(|&&keep| keep).count();
+        assert!((29000..31000).contains(&kept));
+        assert!(rng.dropout_mask(100, 1.0).into_iter().all(|keep| keep));
+        assert!(rng.dropout_mask(100, 0.0).into_iter().all(|keep| !keep));
+    }
+
+    #[test]
+    fn draw_count() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.draw_count(), 0);
+        rng.randrange(0..10);
+        assert_eq!(rng.draw_count(), 1);
+        rng.random();
+        assert_eq!(rng.draw_count(), 2);
+        rng.gaussian(0.0, 1.0);
+        assert_eq!(rng.draw_count(), 4);
+        // the second number of the pair is cached
+        rng.gaussian(0.0, 1.0);
+        assert_eq!(rng.draw_count(), 4);
+        rng.split();
+        assert_eq!(rng.draw_count(), 6);
+    }
+
+    #[test]
+    fn reproducible_seed() {
+        let mut rng: Random<MersenneTwister> = Random::from_u64(10).unwrap();
+        let values: Vec<u32> = (0..100).map(|_| rng.randrange(..)).collect();
+
+        let mut replay: Random<MersenneTwister> = Random::seed(rng.reproducible_seed().unwrap())
+            .unwrap();
+        assert_eq!((0..100).map(|_| replay.randrange(..)).collect::<Vec<u32>>(), values);
+        assert_eq!(rng.split().reproducible_seed(), None);
+    }
+
+    #[test]
+    fn value_noise_2d() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let same: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let other: Random<MersenneTwister> = Random::seed(11).unwrap();
+        let value = rng.value_noise_2d(3, -7);
+        assert_eq!(value, 0.3452848341304858);
+        rng.randrange(..);
+        assert_eq!(rng.value_noise_2d(3, -7), value);
+        assert_eq!(same.value_noise_2d(3, -7), value);
+        assert_ne!(other.value_noise_2d(3, -7), value);
+        assert_ne!(rng.value_noise_2d(-7, 3), value);
+
+        let mut low = 0;
+
+        for x in -50..50 {
+            for y in -50..50 {
+                let value = rng.value_noise_2d(x, y);
+                assert!((0.0..=1.0).contains(&value));
+
+                if value < 0.5 {
+                    low += 1;
+                }
+            }
+        }
+
+        assert!((4800..5200).contains(&low));
+
+        // generators without a seed still get the same key on every run
+        let restored: Random<MersenneTwister> = Random::from_hex(&rng.to_hex()).unwrap();
+        assert_eq!(restored.value_noise_2d(3, -7), value);
+        let split = Random::<MersenneTwister>::seed(10).unwrap().split();
+        let twin = Random::<MersenneTwister>::seed(10).unwrap().split();
+        assert_eq!(split.value_noise_2d(3, -7), twin.value_noise_2d(3, -7));
+        let array: Random<MersenneTwister> = Random::seed_array(&[1, 2]).unwrap();
+        let same_array: Random<MersenneTwister> = Random::seed_array(&[1, 2]).unwrap();
+        assert_eq!(array.value_noise_2d(3, -7), same_array.value_noise_2d(3, -7));
+    }
+
+    #[test]
+    fn random_walk() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let walk = rng.random_walk(1000, 0.5);
+        assert_eq!(walk.len(), 1000);
+        assert_eq!(walk[..4], [0.5, 0.0, -0.5, 0.0]);
+        assert_eq!(walk[0].abs(), 0.5);
+        assert!(walk.windows(2).all(|pair| (pair[1] - pair[0]).abs() == 0.5));
+        assert!(rng.random_walk(0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn seed_array() {
+        let mut rng = Random::seed_array(&[0x123, 0x234, 0x345, 0x456]).unwrap();
+        let mut expected = MersenneTwister::new_from_array(&[0x123, 0x234, 0x345, 0x456]).unwrap();
+        assert_eq!(rng.randrange(..), expected.next_raw());
+        assert_eq!(rng.seed_used(), None);
+        assert!(Random::seed_array(&[]).is_err());
+    }
+
+    #[test]
+    fn full() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values: Vec<u32> = (0..100000).map(|_| rng.full()).collect();
+        assert!(*values.iter().min().unwrap() < 1 << 20);
+        assert!(*values.iter().max().unwrap() > u32::MAX - (1 << 20));
+
+        // only 64 bits are generated at once, the highest ones still get filled
+        let mut rng: Random<XORShift128Plus> = Random::from_u64(10).unwrap();
+        assert!((0..100).map(|_| rng.full()).any(|value| value > u128::MAX / 2));
+    }
+
+    #[test]
+    fn bits() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values: Vec<u32> = (0..1000).map(|_| rng.bits(4)).collect();
+        assert!(values.iter().all(|value| *value < 16));
+        assert!(values.contains(&0) && values.contains(&15));
+        assert_eq!(rng.bits(0), 0);
+        rng.bits(32);
+    }
+
+    #[test]
+    fn randrange_eased() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mean = |rng: &mut Random<MersenneTwister>, power: f64| {
+            let total: u32 = (0..10000).map(|_| rng.randrange_eased(0..100, power)).sum();
+            return total as f64 / 10000.0;
+        };
+
+        // the mean of u^2 is 1/3, and the mean of u^0.5 is 2/3
+        assert!((mean(&mut rng, 1.0) - 49.5).abs() < 1.5);
+        assert!((mean(&mut rng, 2.0) - 33.0).abs() < 1.5);
+        assert!((mean(&mut rng, 0.5) - 66.2).abs() < 1.5);
+        assert!((0..1000).all(|_| rng.randrange_eased(10..=20, 2.0) <= 20));
+
+        let mut rng: Random<Signed> = Random::seed(10).unwrap();
+        let values: Vec<i32> = (0..1000).map(|_| rng.randrange_eased(i32::MIN..=i32::MAX, 1.0))
+            .collect();
+        assert!(values.iter().any(|&value| value < 0) && values.iter().any(|&value| value > 0));
+        // with a power of 2 most numbers fall in the lower half of the range
+        let negative = (0..1000).filter(|_| rng.randrange_eased(i32::MIN..=i32::MAX, 2.0) < 0)
+            .count();
+        assert!(negative > 650);
+    }
+
+    #[test]
+    fn randrange_n() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values: [u32; 3] = rng.randrange_n(0..256);
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(values, [rng.randrange(0..256), rng.randrange(0..256), rng.randrange(0..256)]);
+    }
+
+    #[test]
+    fn rand_grid() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let grid = rng.rand_grid(3, 4, 0..10);
+        assert_eq!(grid.len(), 3);
+        assert!(grid.iter().all(|row| row.len() == 4));
+        assert_eq!(grid[0][0], 7);
+
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.rand_grid(3, 4, 0..10), grid);
+    }
+
+    #[test]
+    fn histogram() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let counts = rng.histogram(0..=9, 10000, 5);
+        assert_eq!(counts.len(), 5);
+        assert_eq!(counts.iter().sum::<usize>(), 10000);
+        assert!(counts.iter().all(|count| (1800..2200).contains(count)));
+
+        let mut rng: Random<Signed> = Random::seed(10).unwrap();
+        let counts = rng.histogram(i32::MIN..=i32::MAX, 10000, 4);
+        assert_eq!(counts.iter().sum::<usize>(), 10000);
+        assert!(counts.iter().all(|count| (2300..2700).contains(count)));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn try_randrange() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert!(rng.try_randrange(5..5).is_err());
+        assert!(rng.try_randrange(10..3).is_err());
+        assert_eq!(rng.try_randrange(0..10), Ok(7));
+        assert_eq!(rng.try_randrange(5..=5), Ok(5));
+    }
+
+    #[test]
+    fn index() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.index(10);
+        assert_eq!(value, Some(7));
+
+        for len in [1, 2, 3, 7, 100, 1000] {
+            let value = rng.index(len).unwrap();
+            assert!(value < len);
+        }
+    }
+
+    #[test]
+    fn index_empty() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.index(0), None);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.random();
+        assert_eq!(value, 0.7713206433158649);
+    }
+
+    #[test]
+    fn random_narrow_algorithm() {
+        let mut rng: Random<crate::minstd::MinStd> = Random::seed(10).unwrap();
+        let mean = (0..10000).map(|_| rng.random()).sum::<f64>() / 10000.0;
+        assert!((mean - 0.5).abs() < 0.02);
+        let mean = (0..10000).map(|_| rng.gaussian(0.0, 1.0)).sum::<f64>() / 10000.0;
+        assert!(mean.abs() < 0.05);
+
+        // only the lowest 64 bits are random, so they are the ones scaled to [0, 1]
+        let mut rng: Random<XORShift128Plus> = Random::from_u64(10).unwrap();
+        let raw = Random::<XORShift128Plus>::from_u64(10).unwrap().randrange(..);
+        assert_eq!(rng.random(), raw as f64 / u64::MAX as f64);
+    }
+
+    #[test]
+    fn uniform() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.uniform(1, 2);
+        assert_eq!(value, 1.7713206433158648);
+    }
+
+    #[test]
+    fn map_uniform() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.map_uniform(|u| u * 2.0), 1.5426412866317298);
+        assert!((0..1000).all(|_| (0.0..=2.0).contains(&rng.map_uniform(|u| u * 2.0))));
+    }
+
+    #[test]
+    fn triangular() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.triangular(1, 7, 4);
+        assert_eq!(value, 4.97115096167447);
+    }
+
+    #[test]
+    fn rand_coefficients() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let coefficients = rng.rand_coefficients(4, -1.0, 1.0);
+        assert_eq!(coefficients.len(), 5);
+        assert_eq!(coefficients[0], 0.5426412866317298);
+        assert!(coefficients.iter().all(|value| (-1.0..=1.0).contains(value)));
+        assert_eq!(rng.rand_coefficients(0, 2.0, 3.0).len(), 1);
+    }
+
+    #[test]
+    fn range_f64() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..1000 {
+            let value = rng.range_f64(-2.5, 7.5);
+            assert!((-2.5..=7.5).contains(&value));
+            let value = rng.range_f64(7.5, -2.5);
+            assert!((-2.5..=7.5).contains(&value));
+        }
+    }
+
+    #[test]
+    fn rand_timestamp() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..1000 {
+            let value = rng.rand_timestamp(1_704_067_200, 1_735_689_600);
+            assert!((1_704_067_200..1_735_689_600).contains(&value));
+        }
+
+        assert_eq!(rng.rand_timestamp(5, 6), 5);
+    }
+
+    #[test]
+    fn gaussian() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.gaussian(0.0, 1.0);
+        assert_eq!(value, -0.21734434779444867);
+    }
+
+    #[test]
+    fn gaussian_pair() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let (x, y) = rng.gaussian_pair(0.0, 1.0);
+        assert_eq!(x, -0.21734434779444867);
+        assert_eq!(y, 0.6870688893067801);
+
+        // the pair doesn't leave a spare number for gaussian
+        let mut other: Random<MersenneTwister> = Random::seed(10).unwrap();
+        other.gaussian_pair(0.0, 1.0);
+        assert_eq!(rng.gaussian(0.0, 1.0), other.gaussian(0.0, 1.0));
+    }
+
+    #[test]
+    fn gaussian_mixture() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.gaussian_mixture(0.3, -5.0, 1.0, 5.0, 2.0);
+        assert_eq!(value, 8.08245061852239);
+    }
+
+    #[test]
+    fn gaussian_mixture_modes() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let first = (0..10000)
+            .filter(|_| rng.gaussian_mixture(0.3, -10.0, 1.0, 10.0, 1.0) < 0.0)
+            .count();
+        assert!((2800..3200).contains(&first));
+    }
+
+    #[test]
+    fn gaussian_correlated() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for rho in [-0.8, 0.0, 0.5, 1.0] {
+            let pairs: Vec<(f64, f64)> = (0..10000).map(|_| rng.gaussian_correlated(rho)).collect();
+            let mean_x = pairs.iter().map(|pair| pair.0).sum::<f64>() / 10000.0;
+            let mean_y = pairs.iter().map(|pair| pair.1).sum::<f64>() / 10000.0;
+            let mut covariance = 0.0;
+            let mut variance_x = 0.0;
+            let mut variance_y = 0.0;
+
+            for (x, y) in &pairs {
+                covariance += (x - mean_x) * (y - mean_y);
+                variance_x += (x - mean_x) * (x - mean_x);
+                variance_y += (y - mean_y) * (y - mean_y);
+            }
+
+            let correlation = covariance / (variance_x * variance_y).sqrt();
+            assert!((correlation - rho).abs() < 0.03);
+        }
+    }
+
+    #[test]
+    fn gaussian_int() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.gaussian_int(100.0, 10.0);
+        assert_eq!(value, 98);
+    }
+
+    #[test]
+    fn gaussian_int_mean() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let total: i64 = (0..10000).map(|_| rng.gaussian_int(50.0, 5.0)).sum();
+        let mean = total as f64 / 10000.0;
+        assert!((mean - 50.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn bounded_gaussian_int() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..10000 {
+            let value = rng.bounded_gaussian_int(10.0, 3.0, 3, 18);
+            assert!((3..=18).contains(&value));
+        }
+
+        // bounds far away from the mean end up clamped
+        assert_eq!(rng.bounded_gaussian_int(0.0, 1.0, 50, 60), 50);
+    }
+
+    #[test]
+    fn gaussian_vec() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values = rng.gaussian_vec(5, 1.0, 2.0);
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let expected: Vec<f64> = (0..5).map(|_| rng.gaussian(1.0, 2.0)).collect();
+        assert_eq!(values, expected);
+        assert_eq!(values[0], 0.5653113044111027);
+    }
+
+    #[test]
+    fn gaussian_clamped() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..10000 {
+            let value = rng.gaussian_clamped(50.0, 20.0, 0.0, 100.0);
+            assert!((0.0..=100.0).contains(&value));
+        }
+
+        // bounds far away from the mean end up clamped
+        let value = rng.gaussian_clamped(0.0, 1.0, 50.0, 60.0);
+        assert_eq!(value, 50.0);
+    }
+
+    #[test]
+    fn reject_sample() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        // the chance of accepting a number grows with it, giving a triangular shape
+        let accept = |x: f64| (x * 100.0).fract() < x / 10.0;
+
+        for _ in 0..1000 {
+            let value = rng.reject_sample(0.0, 10.0, accept, 1000).unwrap();
+            assert!((0.0..=10.0).contains(&value));
+            assert!(accept(value));
+        }
+
+        assert_eq!(rng.reject_sample(0.0, 10.0, |_| false, 100), None);
+    }
+
+    #[test]
+    fn pareto() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.pareto(1.0, 3.0);
+        assert_eq!(value, 1.6352758110896812);
+    }
+
+    #[test]
+    fn log_uniform() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut decades = [0; 4];
+
+        for _ in 0..40000 {
+            let value = rng.log_uniform(1.0, 10000.0);
+            assert!((1.0..=10000.0).contains(&value));
+            decades[(value.log10() as usize).min(3)] += 1;
+        }
+
+        assert!(decades.iter().all(|count| (9500..10500).contains(count)));
+        assert_eq!(rng.log_uniform(5.0, 5.0), 5.0);
+    }
+
+    #[test]
+    fn negative_binomial() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.negative_binomial(3, 0.25);
+        assert_eq!(value, 17);
+        assert_eq!(rng.negative_binomial(5, 1.0), 0);
+    }
+
+    #[test]
+    fn negative_binomial_mean() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let total: u64 = (0..10000).map(|_| rng.negative_binomial(3, 0.25)).sum();
+        let mean = total as f64 / 10000.0;
+        // r * (1 - p) / p
+        assert!((mean - 9.0).abs() < 0.3);
+    }
+
+    #[test]
+    fn hypergeometric() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.hypergeometric(52, 4, 5).unwrap();
+        assert_eq!(value, 1);
+        assert_eq!(rng.hypergeometric(10, 10, 4), Ok(4));
+        assert_eq!(rng.hypergeometric(10, 3, 10), Ok(3));
+        assert!(rng.hypergeometric(10, 11, 4).is_err());
+        assert!(rng.hypergeometric(10, 3, 11).is_err());
+    }
+
+    #[test]
+    fn hypergeometric_mean() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let total: u64 = (0..10000).map(|_| rng.hypergeometric(50, 20, 10).unwrap()).sum();
+        let mean = total as f64 / 10000.0;
+        // draws * successes / population
+        assert!((mean - 4.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn dirichlet() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values = rng.dirichlet(&[0.5, 1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(values.len(), 4);
+        assert!((values.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(values.iter().all(|value| (0.0..=1.0).contains(value)));
+        assert!(rng.dirichlet(&[]).is_err());
+        assert!(rng.dirichlet(&[1.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn chi_square() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.chi_square(3.0), 1.8946041511597354);
+
+        let mean = (0..100000).map(|_| rng.chi_square(4.0)).sum::<f64>() / 100000.0;
+        assert!((mean - 4.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn student_t() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.student_t(5.0), -0.18793876963548994);
+
+        let values: Vec<f64> = (0..100000).map(|_| rng.student_t(30.0)).collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>()
+            / values.len() as f64;
+        assert!(mean.abs() < 0.02);
+        assert!((variance - 30.0 / 28.0).abs() < 0.03);
+    }
+
+    #[test]
+    fn f_distribution() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.f_distribution(5.0, 10.0), 0.5898908046270239);
+
+        let mean = (0..100000).map(|_| rng.f_distribution(5.0, 10.0)).sum::<f64>() / 100000.0;
+        assert!((mean - 10.0 / 8.0).abs() < 0.03);
+    }
+
+    #[test]
+    fn dirichlet_mean() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let total: f64 = (0..10000).map(|_| rng.dirichlet(&[0.5, 1.0, 2.5]).unwrap()[2]).sum();
+        // alpha / sum of alphas
+        assert!((total / 10000.0 - 0.625).abs() < 0.01);
+    }
+
+    #[test]
+    fn zipf() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut counts = [0; 10];
+
+        for _ in 0..10000 {
+            let rank = rng.zipf(10, 1.0);
+            assert!((1..=10).contains(&rank));
+            counts[rank as usize - 1] += 1;
+        }
+
+        assert!(counts.windows(2).all(|pair| pair[0] > pair[1]));
+        // 1 / (1 + 1/2 + ... + 1/10)
+        assert!((counts[0] as f64 / 10000.0 - 0.3414).abs() < 0.02);
+        assert_eq!(rng.zipf(1, 2.0), 1);
+    }
+
+    #[test]
+    fn pareto_minimum() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..10000 {
+            assert!(rng.pareto(2.5, 1.5) >= 2.5);
+        }
+    }
+
+    #[test]
+    fn point_in_triangle() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut corner = 0;
+
+        for _ in 0..10000 {
+            let (x, y) = rng.point_in_triangle((0.0, 0.0), (4.0, 0.0), (0.0, 4.0));
+            assert!(x >= 0.0 && y >= 0.0 && x + y <= 4.0 + 1e-9);
+
+            // the corner triangle has a quarter of the area
+            if x + y <= 2.0 {
+                corner += 1;
+            }
+        }
+
+        assert!((2300..2700).contains(&corner));
+    }
+
+    #[test]
+    fn point_in_disk() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut inner = 0;
+        let mut right = 0;
+
+        for _ in 0..10000 {
+            let (x, y) = rng.point_in_disk(2.0);
+            let distance = (x * x + y * y).sqrt();
+            assert!(distance <= 2.0);
+
+            if distance <= 1.0 {
+                inner += 1;
+            }
+
+            if x > 0.0 {
+                right += 1;
+            }
+        }
+
+        // the inner disk has a quarter of the area
+        assert!((2300..2700).contains(&inner));
+        assert!((4700..5300).contains(&right));
+    }
+
+    #[test]
+    fn unit_vector_2d() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..1000 {
+            let (x, y) = rng.unit_vector_2d();
+            assert!(((x * x + y * y).sqrt() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn unit_vector_3d() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        for _ in 0..1000 {
+            let (x, y, z) = rng.unit_vector_3d();
+            assert!(((x * x + y * y + z * z).sqrt() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn roll_pool() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let pool = rng.roll_pool(8, 10);
+        assert_eq!(pool, vec![8, 6, 3, 8, 3, 6, 8, 3]);
+
+        // MinStd only generates 31 bits, fewer than the sides of the dice
+        let mut rng: Random<crate::minstd::MinStd> = Random::seed(10).unwrap();
+        let pool = rng.roll_pool(100, u32::MAX);
+        assert!(pool.iter().all(|die| *die >= 1));
+        assert!(pool.iter().any(|die| *die > 1 << 31));
+    }
+
+    #[test]
+    fn roll_crit() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let rolls: Vec<(u32, bool, bool)> = (0..8).map(|_| rng.roll_crit(10)).collect();
+        assert_eq!(rolls[0], (8, false, false));
+        assert_eq!(rolls[5], (6, false, false));
+
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let coins: Vec<(u32, bool, bool)> = (0..5).map(|_| rng.roll_crit(2)).collect();
+        assert_eq!(coins[0], (2, false, true));
+        assert_eq!(coins[4], (1, true, false));
+        assert_eq!(rng.roll_crit(1), (1, true, true));
+    }
+
+    #[test]
+    fn randbytes() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.randbytes(4);
+        assert_eq!(value, vec![9, 69, 117, 197]);
+    }
+
+    #[test]
+    fn randbytes_calls() {
+        let mut rng: Random<Counting> = Random::seed(10).unwrap();
+        rng.randbytes(100);
+        assert_eq!(rng.algorithm.calls, 25);
+    }
+
+    #[test]
+    fn rand_color() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut same: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.rand_color(), (9, 125, 228));
+        assert_eq!(same.rand_color_hex(), "#097de4");
+    }
+
+    #[test]
+    fn rand_ipv4() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut same: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.rand_ipv4(), [9, 125, 228, 15]);
+        assert_eq!(same.rand_ipv4_string(), "9.125.228.15");
+    }
+
+    #[test]
+    fn rand_uuid() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let id = rng.rand_uuid();
+        assert_eq!(id, "094575c5-7d9c-4b4c-a4ff-4f050f729d7e");
+
+        let groups: Vec<&str> = id.split('-').collect();
+        let lengths: Vec<usize> = groups.iter().map(|group| group.len()).collect();
+        assert_eq!(lengths, vec![8, 4, 4, 4, 12]);
+        assert!(groups.concat().chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+        assert!(groups[2].starts_with('4'));
+        assert!(groups[3].starts_with(['8', '9', 'a', 'b']));
+    }
+
+    #[test]
+    fn rand_printable() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let text = rng.rand_printable(1000);
+        assert_eq!(text.len(), 1000);
+        assert!(text.chars().all(|c| (' '..='~').contains(&c)));
+        assert!(text.contains(' '));
+        assert!(text.contains('~'));
+    }
+
+    #[test]
+    fn rand_array() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value: [u8; 4] = rng.rand_array::<4>();
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(value.to_vec(), rng.randbytes(4));
+        assert_eq!(value, [9, 69, 117, 197]);
+    }
+
+    #[test]
+    fn rand_graycode_byte() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut previous = 0;
+        let mut seen: HashSet<u8> = HashSet::new();
+
+        for _ in 0..10000 {
+            let value = rng.rand_graycode_byte();
+            assert_eq!((value ^ previous).count_ones(), 1);
+            seen.insert(value);
+            previous = value;
+        }
+
+        assert_eq!(seen.len(), 256);
+
+        // the last byte is kept in the state
+        let mut restored: Random<MersenneTwister> = Random::restore(rng.state());
+        let mut decoded: Random<MersenneTwister> = Random::from_hex(&rng.to_hex()).unwrap();
+        let value = rng.rand_graycode_byte();
+        assert_eq!((value ^ previous).count_ones(), 1);
+        assert_eq!(restored.rand_graycode_byte(), value);
+        assert_eq!(decoded.rand_graycode_byte(), value);
+    }
+
+    #[test]
+    fn byte_stream() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value: Vec<u8> = rng.byte_stream().take(8).collect();
+        assert_eq!(value, vec![9, 69, 117, 197, 125, 156, 123, 76]);
+    }
+
+    #[test]
+    fn byte_stream_matches_randbytes() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let bytes = rng.randbytes(10);
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value: Vec<u8> = rng.byte_stream().take(10).collect();
+        assert_eq!(value, bytes);
+    }
+
+    #[test]
+    fn rand_bits() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let value = rng.rand_bits(40);
+        let expected: Vec<bool> = "1001000010100010101011101010001110111110"
+            .chars().map(|bit| bit == '1').collect();
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn sticky_flip() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        // fraction of consecutive states that are equal
+        let mut correlation = |stay_prob: f64| {
+            let mut state = false;
+            let mut same = 0;
+
+            for _ in 0..10000 {
+                let next = rng.sticky_flip(state, stay_prob);
+
+                if next == state {
+                    same += 1;
+                }
+
+                state = next;
+            }
+
+            return same as f64 / 10000.0;
+        };
+
+        let low = correlation(0.2);
+        let middle = correlation(0.5);
+        let high = correlation(0.9);
+        assert!(low < middle && middle < high);
+        assert!((high - 0.9).abs() < 0.02);
+        assert_eq!(correlation(1.0), 1.0);
+    }
+
+    #[test]
+    fn categorical() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut counts = [0; 3];
+
+        for _ in 0..10000 {
+            counts[rng.categorical(&[0.7, 0.2, 0.1]).unwrap()] += 1;
+        }
+
+        assert!((6800..7200).contains(&counts[0]));
+        assert!((1850..2150).contains(&counts[1]));
+        assert!((900..1100).contains(&counts[2]));
+    }
+
+    #[test]
+    fn categorical_invalid() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert!(rng.categorical(&[0.5, 0.6]).is_err());
+        assert!(rng.categorical(&[1.5, -0.5]).is_err());
+        assert!(rng.categorical(&[]).is_err());
+        assert!(rng.categorical(&[f64::NAN]).is_err());
+        assert!(rng.categorical(&[f64::INFINITY, 0.0]).is_err());
+    }
+
+    #[test]
+    fn from_cdf() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let cdf = [(1.0, 0.3), (2.0, 1.0)];
+        let mut ones = 0;
+
+        for _ in 0..10000 {
+            let value = rng.from_cdf(&cdf).unwrap();
+            assert!((1.0..=2.0).contains(&value));
+
+            if value == 1.0 {
+                ones += 1;
+            }
+        }
+
+        assert!((2800..3200).contains(&ones));
+    }
+
+    #[test]
+    fn from_cdf_invalid() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert!(rng.from_cdf(&[]).is_err());
+        assert!(rng.from_cdf(&[(1.0, 0.5), (2.0, 0.4), (3.0, 1.0)]).is_err());
+        assert!(rng.from_cdf(&[(1.0, 0.5), (2.0, 0.9)]).is_err());
+    }
+
+    #[test]
+    fn choose() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let vector = vec![
+            "This".to_string(), "is".to_string(), "a".to_string(), "test".to_string()
+        ];
+        let chosen = rng.choose(&vector);
+        assert_eq!(chosen, "is");
+    }
+
+    #[test]
+    fn choose_value() {
+        let values = [2, 4, 8, 16];
+        let mut seen: HashSet<u32> = HashSet::new();
+
+        for seed in 0..20 {
+            let mut rng: Random<MersenneTwister> = Random::seed(seed).unwrap();
+            seen.insert(rng.choose_value(&values).unwrap());
+            assert_eq!(rng.choose_value::<u32>(&[]), None);
+        }
+
+        assert_eq!(seen, HashSet::from(values));
+    }
+
+    #[test]
+    fn bootstrap() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let resample = rng.bootstrap(&[1, 2, 3, 4], 6);
+        assert_eq!(resample, vec![2, 2, 1, 4, 1, 2]);
+        assert!(rng.bootstrap::<u32>(&[], 6).is_empty());
+    }
+
+    #[test]
+    fn choose_owned() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        enum Direction { North, South, East, West }
+
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let directions = [Direction::North, Direction::South, Direction::East, Direction::West];
+        assert_eq!(rng.choose_owned(&directions), Some(Direction::South));
+        assert_eq!(rng.choose_owned::<Direction>(&[]), None);
+    }
+
+    #[test]
+    fn random_window() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let data: Vec<u32> = (0..10).collect();
+        let window = rng.random_window(&data, 4).unwrap();
+        assert_eq!(window, &[2, 3, 4, 5]);
+
+        for _ in 0..100 {
+            let window = rng.random_window(&data, 4).unwrap();
+            assert_eq!(window.len(), 4);
+            assert!(window[0] <= 6);
+        }
+
+        assert_eq!(rng.random_window(&data, 10).unwrap(), &data[..]);
+        assert!(rng.random_window(&data, 11).is_err());
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut vector = vec![
+            "This".to_string(), "is".to_string(), "a".to_string(), "test".to_string()
+        ];
+        rng.shuffle(&mut vector);
+        assert_eq!(vector, vec![
+            "test".to_string(), "a".to_string(), "This".to_string(), "is".to_string()
+        ]);
+    }
+
+    #[test]
+    fn shuffle_vecdeque() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut deque: VecDeque<u32> = (0..10).collect();
+        rng.shuffle(&mut deque);
+        assert_eq!(deque, vec![9, 2, 6, 8, 5, 0, 1, 4, 3, 7]);
+
+        let mut sorted: Vec<u32> = deque.into_iter().collect();
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn shuffle_empty() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut vector: Vec<u32> = Vec::new();
+        rng.shuffle(&mut vector);
+        assert!(vector.is_empty());
+    }
+
+    #[test]
+    fn shuffle_same_for_any_type() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut numbers = vec![0, 1, 2, 3, 4];
+        rng.shuffle(&mut numbers);
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut letters = vec!['a', 'b', 'c', 'd', 'e'];
+        rng.shuffle(&mut letters);
+        let expected: Vec<char> = numbers.iter().map(|&i| ['a', 'b', 'c', 'd', 'e'][i]).collect();
+        assert_eq!(letters, expected);
+    }
+
+    #[test]
+    fn partition() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let data: Vec<u32> = (0..11).collect();
+        let groups = rng.partition(&data, 3).unwrap();
+        assert_eq!(groups, vec![vec![3, 4, 1, 0], vec![8, 9, 2, 7], vec![6, 5, 10]]);
+
+        let sizes: Vec<usize> = groups.iter().map(|group| group.len()).collect();
+        assert_eq!(sizes, vec![4, 4, 3]);
+        let mut values: Vec<u32> = groups.concat();
+        values.sort();
+        assert_eq!(values, data);
+
+        assert_eq!(rng.partition(&data[..2], 4).unwrap(), vec![vec![1], vec![0], vec![], vec![]]);
+        assert!(rng.partition(&data, 0).is_err());
+    }
+
+    #[test]
+    fn shuffle_together() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut numbers: Vec<u32> = (0..10).collect();
+        let mut squares: Vec<u32> = (0..10).map(|x| x * x).collect();
+        rng.shuffle_together(&mut numbers, &mut squares).unwrap();
+        assert!(numbers.iter().zip(&squares).all(|(x, square)| x * x == *square));
+
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let mut expected: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut expected);
+        assert_eq!(numbers, expected);
+
+        assert!(rng.shuffle_together(&mut numbers, &mut [0; 3]).is_err());
+    }
+
+    #[test]
+    fn permutation() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let values = rng.permutation(10);
+        assert_eq!(values, vec![9, 2, 6, 8, 5, 0, 1, 4, 3, 7]);
+
+        let mut sorted = values.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn distinct_indices() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+
+        // linear scan, set and shuffle
+        for (range_len, k) in [(1000, 5), (1000, 100), (1000, 900)] {
+            let indices = rng.distinct_indices(range_len, k).unwrap();
+            let distinct: HashSet<&usize> = indices.iter().collect();
+            assert_eq!(indices.len(), k);
+            assert_eq!(distinct.len(), k);
+            assert!(indices.iter().all(|index| *index < range_len));
+        }
+
+        assert!(rng.distinct_indices(5, 6).is_err());
+    }
+
+    #[test]
+    fn sample_ordered() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let vector: Vec<u32> = (0..10).collect();
+        let sample = rng.sample_ordered(&vector, 4).unwrap();
+        assert_eq!(sample, vec![&1, &2, &5, &7]);
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(rng.sample_ordered(&vector, 11).is_err());
+    }
+
+    #[test]
+    fn reservoir() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let sample = rng.reservoir(0..1000, 10);
+        let distinct: HashSet<&u32> = sample.iter().collect();
+        assert_eq!(sample.len(), 10);
+        assert_eq!(distinct.len(), 10);
+
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        assert_eq!(rng.reservoir(0..1000, 10), sample);
+        assert_eq!(rng.reservoir(0..5, 10), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn wide_index() {
+        // MinStd only generates 31 bits, so longer iterators need wider positions
+        let mut rng: Random<crate::minstd::MinStd> = Random::seed(10).unwrap();
+        let indices: Vec<usize> = (0..100).map(|_| rng.wide_index(1 << 40)).collect();
+        assert!(indices.iter().all(|index| *index < 1 << 40));
+        assert!(indices.iter().any(|index| *index >= 1 << 31));
+        assert!((0..100).all(|_| rng.wide_index(10) < 10));
+    }
+
+    #[test]
+    fn sample() {
         let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
         let vector = vec![
             "This".to_string(), "is".to_string(), "a".to_string(), "test".to_string()
         ];
         let sample = rng.sample(&vector, 2).unwrap();
         assert!(sample.len() == 2);
-        assert_eq!(*sample[0], vector[0]);
-        assert_eq!(*sample[1], vector[2]);
+        assert_eq!(*sample[0], vector[1]);
+        assert_eq!(*sample[1], vector[0]);
+    }
+
+    #[test]
+    fn sample_almost_everything() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let vector: Vec<u32> = (0..100000).collect();
+        let sample = rng.sample(&vector, vector.len() - 1).unwrap();
+        let distinct: HashSet<&u32> = sample.iter().copied().collect();
+        assert_eq!(sample.len(), vector.len() - 1);
+        assert_eq!(distinct.len(), vector.len() - 1);
     }
 }
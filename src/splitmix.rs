@@ -0,0 +1,68 @@
+// Implementation for the SplitMix64 algorithm, used to expand and mix seeds
+// https://prng.di.unimi.it/splitmix64.c
+/// SplitMix64 algorithm.
+pub(crate) struct SplitMix64 {
+    state: u64
+}
+
+impl SplitMix64 {
+    /// Creates a new SplitMix64 with the given seed, any value is valid.
+    pub(crate) fn new(seed: u64) -> SplitMix64 {
+        return SplitMix64 { state: seed };
+    }
+
+    /// Returns the next number of the sequence.
+    pub(crate) fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+
+        return z ^ (z >> 31);
+    }
+}
+
+// 32 bits variant of SplitMix, with the constants of the lowbias32 hash
+// https://github.com/skeeto/hash-prospector
+/// SplitMix32 algorithm.
+pub(crate) struct SplitMix32 {
+    state: u32
+}
+
+impl SplitMix32 {
+    /// Creates a new SplitMix32 with the given seed, any value is valid.
+    pub(crate) fn new(seed: u32) -> SplitMix32 {
+        return SplitMix32 { state: seed };
+    }
+
+    /// Returns the next number of the sequence.
+    pub(crate) fn next(&mut self) -> u32 {
+        self.state = self.state.wrapping_add(0x9E3779B9);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 16)).wrapping_mul(0x21F0AAAD);
+        z = (z ^ (z >> 15)).wrapping_mul(0x735A2D97);
+
+        return z ^ (z >> 15);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitmix64_value() {
+        let mut random = SplitMix64::new(1234567);
+        assert_eq!(random.next(), 6457827717110365317);
+        assert_eq!(random.next(), 3203168211198807973);
+    }
+
+    #[test]
+    fn splitmix32_value() {
+        let mut random = SplitMix32::new(1234567);
+        assert_eq!(random.next(), 4101310354);
+        assert_eq!(random.next(), 1937531806);
+    }
+}
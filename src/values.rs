@@ -1,20 +1,44 @@
-use std::ops::{Add, Sub, Range, RangeInclusive, RangeFrom, RangeTo, RangeToInclusive, RangeFull};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::ops::{Add, Sub, Rem, Shl, Shr, BitXor};
+use std::ops::{Range, RangeInclusive, RangeFrom, RangeTo, RangeToInclusive, RangeFull};
+
+use crate::splitmix::SplitMix64;
 
 /// The `ValidRandomNumber` trait.
 /// 
 /// It defines what makes a type or struct a valid random number.
 /// 
 /// Currently implemented for:
+/// * u8
+/// * u16
 /// * u32
 /// * u64
 /// * u128
-pub trait ValidRandomNumber: Copy + Add<Self, Output = Self> + Sub<Self, Output = Self> {
+/// * usize
+/// * i8
+/// * i16
+/// * i32
+/// * i64
+/// * i128
+/// * isize
+/// 
+/// Signed types are handled as their unsigned counterparts when fitting numbers to a range, so
+/// ranges can cross zero or cover the whole type.
+pub trait ValidRandomNumber:
+    Copy + PartialOrd + Add<Self, Output = Self> + Sub<Self, Output = Self>
+    + Rem<Self, Output = Self> + Shl<u32, Output = Self> + Shr<u32, Output = Self>
+    + BitXor<Self, Output = Self>
+{
     /// Returns the representation of a zero for the given type.
     fn zero() -> Self;
 
     /// Returns the representation of a one for the given type.
     fn one() -> Self;
 
+    /// Returns the minimum value representation.
+    fn min() -> Self;
+
     /// Returns the maximum value representation.
     fn max() -> Self;
 
@@ -22,17 +46,161 @@ pub trait ValidRandomNumber: Copy + Add<Self, Output = Self> + Sub<Self, Output
     /// of your type.
     fn byte_max() -> Self;
 
-    /// Returns a convertion to `u8`.
+    /// Returns a convertion to `u8`, truncating values bigger than 255.
     fn to_u8(self) -> u8;
 
+    /// Returns a convertion to `u8`, or `None` if the value is negative or bigger than 255.
+    fn checked_to_u8(self) -> Option<u8> {
+        if self < Self::zero() || self > Self::byte_max() {
+            return None;
+        }
+
+        return Some(self.to_u8());
+    }
+
     /// Returns a convertion to `usize`.
     fn to_usize(self) -> usize;
 
+    /// Returns a convertion to `u64`.
+    fn to_u64(self) -> u64;
+
     /// Returns a convertion to `f64`.
     fn to_f64(self) -> f64;
 
     /// Creates this type from a `usize` value.
     fn from_usize(value: usize) -> Self;
+
+    /// Returns the amount of bits used by the given type.
+    fn bits() -> u32 {
+        return (std::mem::size_of::<Self>() * 8) as u32;
+    }
+
+    /// Returns the addition of `other`, wrapping around at the boundary of the type.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Returns the subtraction of `other`, wrapping around at the boundary of the type.
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// Returns the remainder of the division by `other`, reading both values as unsigned numbers of
+    /// the same size, so it's never negative for signed types.
+    fn unsigned_rem(self, other: Self) -> Self {
+        return self % other;
+    }
+
+    /// Returns a convertion to `f64`, reading the value as an unsigned number of the same size, so
+    /// the width of a signed range is never negative.
+    fn unsigned_to_f64(self) -> f64 {
+        return self.to_f64();
+    }
+
+    /// Returns the distance from `start` to `end`, or `None` if `end` is smaller than `start`.
+    fn checked_width(start: Self, end: Self) -> Option<Self> {
+        if end < start {
+            return None;
+        }
+
+        return Some(end.wrapping_sub(start));
+    }
+}
+
+impl ValidRandomNumber for u8 {
+    fn zero() -> u8 {
+        return 0;
+    }
+
+    fn one() -> u8 {
+        return 1;
+    }
+
+    fn min() -> u8 {
+        return u8::MIN;
+    }
+
+    fn max() -> u8 {
+        return u8::MAX;
+    }
+
+    fn byte_max() -> u8 {
+        return 255;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> u8 {
+        return value as u8;
+    }
+
+    fn wrapping_add(self, other: u8) -> u8 {
+        return u8::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: u8) -> u8 {
+        return u8::wrapping_sub(self, other);
+    }
+}
+
+impl ValidRandomNumber for u16 {
+    fn zero() -> u16 {
+        return 0;
+    }
+
+    fn one() -> u16 {
+        return 1;
+    }
+
+    fn min() -> u16 {
+        return u16::MIN;
+    }
+
+    fn max() -> u16 {
+        return u16::MAX;
+    }
+
+    fn byte_max() -> u16 {
+        return 255;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> u16 {
+        return value as u16;
+    }
+
+    fn wrapping_add(self, other: u16) -> u16 {
+        return u16::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: u16) -> u16 {
+        return u16::wrapping_sub(self, other);
+    }
 }
 
 impl ValidRandomNumber for u32 {
@@ -44,6 +212,10 @@ impl ValidRandomNumber for u32 {
         return 1;
     }
 
+    fn min() -> u32 {
+        return u32::MIN;
+    }
+
     fn max() -> u32 {
         return u32::MAX;
     }
@@ -60,6 +232,10 @@ impl ValidRandomNumber for u32 {
         return self as usize;
     }
 
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
     fn to_f64(self) -> f64 {
         return self as f64;
     }
@@ -67,6 +243,14 @@ impl ValidRandomNumber for u32 {
     fn from_usize(value: usize) -> u32 {
         return value as u32;
     }
+
+    fn wrapping_add(self, other: u32) -> u32 {
+        return u32::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: u32) -> u32 {
+        return u32::wrapping_sub(self, other);
+    }
 }
 
 impl ValidRandomNumber for u64 {
@@ -78,6 +262,10 @@ impl ValidRandomNumber for u64 {
         return 1;
     }
 
+    fn min() -> u64 {
+        return u64::MIN;
+    }
+
     fn max() -> u64 {
         return u64::MAX;
     }
@@ -94,6 +282,10 @@ impl ValidRandomNumber for u64 {
         return self as usize;
     }
 
+    fn to_u64(self) -> u64 {
+        return self;
+    }
+
     fn to_f64(self) -> f64 {
         return self as f64;
     }
@@ -101,6 +293,14 @@ impl ValidRandomNumber for u64 {
     fn from_usize(value: usize) -> u64 {
         return value as u64;
     }
+
+    fn wrapping_add(self, other: u64) -> u64 {
+        return u64::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: u64) -> u64 {
+        return u64::wrapping_sub(self, other);
+    }
 }
 
 impl ValidRandomNumber for u128 {
@@ -112,6 +312,10 @@ impl ValidRandomNumber for u128 {
         return 1;
     }
 
+    fn min() -> u128 {
+        return u128::MIN;
+    }
+
     fn max() -> u128 {
         return u128::MAX;
     }
@@ -128,6 +332,10 @@ impl ValidRandomNumber for u128 {
         return self as usize;
     }
 
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
     fn to_f64(self) -> f64 {
         return self as f64;
     }
@@ -135,78 +343,945 @@ impl ValidRandomNumber for u128 {
     fn from_usize(value: usize) -> u128 {
         return value as u128;
     }
-}
 
-/// The `ValidRandomRange` trait.
-/// 
-/// It defines what makes a struct a valid range to use with a type that implements
-/// `ValidRandomNumber`.
-/// 
-/// Currently implemented for all `Range` structs.
-pub trait ValidRandomRange<T: ValidRandomNumber> {
-    /// Returns the starting value of the range.
-    fn _start(&self) -> T;
+    fn wrapping_add(self, other: u128) -> u128 {
+        return u128::wrapping_add(self, other);
+    }
 
-    /// Returns the last value of the range.
-    fn _end(&self) -> T;
+    fn wrapping_sub(self, other: u128) -> u128 {
+        return u128::wrapping_sub(self, other);
+    }
 }
 
-impl<T: ValidRandomNumber> ValidRandomRange<T> for Range<T> {
-    fn _start(&self) -> T {
-        return self.start;
+// Its bounds and bits depend on the pointer width of the platform.
+impl ValidRandomNumber for usize {
+    fn zero() -> usize {
+        return 0;
     }
 
-    fn _end(&self) -> T {
-        return self.end;
+    fn one() -> usize {
+        return 1;
     }
-}
 
-impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeInclusive<T> {
-    fn _start(&self) -> T {
-        return *self.start();
+    fn min() -> usize {
+        return usize::MIN;
     }
 
-    fn _end(&self) -> T {
-        return *self.end() + T::one();
+    fn max() -> usize {
+        return usize::MAX;
     }
-}
 
-impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeFrom<T> {
-    fn _start(&self) -> T {
-        return self.start;
+    fn byte_max() -> usize {
+        return 255;
     }
 
-    fn _end(&self) -> T {
-        return T::max();
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> usize {
+        return value;
+    }
+
+    fn wrapping_add(self, other: usize) -> usize {
+        return usize::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: usize) -> usize {
+        return usize::wrapping_sub(self, other);
     }
 }
 
-impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeTo<T> {
-    fn _start(&self) -> T {
-        return T::zero();
+impl ValidRandomNumber for i8 {
+    fn zero() -> i8 {
+        return 0;
     }
 
-    fn _end(&self) -> T {
-        return self.end;
+    fn one() -> i8 {
+        return 1;
+    }
+
+    fn min() -> i8 {
+        return i8::MIN;
+    }
+
+    fn max() -> i8 {
+        return i8::MAX;
+    }
+
+    fn byte_max() -> i8 {
+        return 127;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> i8 {
+        return value as i8;
+    }
+
+    fn wrapping_add(self, other: i8) -> i8 {
+        return i8::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: i8) -> i8 {
+        return i8::wrapping_sub(self, other);
+    }
+
+    fn unsigned_rem(self, other: i8) -> i8 {
+        return (self as u8 % other as u8) as i8;
+    }
+
+    fn unsigned_to_f64(self) -> f64 {
+        return self as u8 as f64;
     }
 }
 
-impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeToInclusive<T> {
-    fn _start(&self) -> T {
-        return T::zero();
+impl ValidRandomNumber for i16 {
+    fn zero() -> i16 {
+        return 0;
     }
 
-    fn _end(&self) -> T {
-        return self.end;
+    fn one() -> i16 {
+        return 1;
+    }
+
+    fn min() -> i16 {
+        return i16::MIN;
+    }
+
+    fn max() -> i16 {
+        return i16::MAX;
+    }
+
+    fn byte_max() -> i16 {
+        return 255;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> i16 {
+        return value as i16;
+    }
+
+    fn wrapping_add(self, other: i16) -> i16 {
+        return i16::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: i16) -> i16 {
+        return i16::wrapping_sub(self, other);
+    }
+
+    fn unsigned_rem(self, other: i16) -> i16 {
+        return (self as u16 % other as u16) as i16;
+    }
+
+    fn unsigned_to_f64(self) -> f64 {
+        return self as u16 as f64;
     }
 }
 
-impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeFull {
-    fn _start(&self) -> T {
-        return T::zero();
+impl ValidRandomNumber for i32 {
+    fn zero() -> i32 {
+        return 0;
     }
 
-    fn _end(&self) -> T {
-        return T::max();
+    fn one() -> i32 {
+        return 1;
+    }
+
+    fn min() -> i32 {
+        return i32::MIN;
+    }
+
+    fn max() -> i32 {
+        return i32::MAX;
+    }
+
+    fn byte_max() -> i32 {
+        return 255;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> i32 {
+        return value as i32;
+    }
+
+    fn wrapping_add(self, other: i32) -> i32 {
+        return i32::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: i32) -> i32 {
+        return i32::wrapping_sub(self, other);
+    }
+
+    fn unsigned_rem(self, other: i32) -> i32 {
+        return (self as u32 % other as u32) as i32;
+    }
+
+    fn unsigned_to_f64(self) -> f64 {
+        return self as u32 as f64;
+    }
+}
+
+impl ValidRandomNumber for i64 {
+    fn zero() -> i64 {
+        return 0;
+    }
+
+    fn one() -> i64 {
+        return 1;
+    }
+
+    fn min() -> i64 {
+        return i64::MIN;
+    }
+
+    fn max() -> i64 {
+        return i64::MAX;
+    }
+
+    fn byte_max() -> i64 {
+        return 255;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> i64 {
+        return value as i64;
+    }
+
+    fn wrapping_add(self, other: i64) -> i64 {
+        return i64::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: i64) -> i64 {
+        return i64::wrapping_sub(self, other);
+    }
+
+    fn unsigned_rem(self, other: i64) -> i64 {
+        return (self as u64 % other as u64) as i64;
+    }
+
+    fn unsigned_to_f64(self) -> f64 {
+        return self as u64 as f64;
+    }
+}
+
+impl ValidRandomNumber for i128 {
+    fn zero() -> i128 {
+        return 0;
+    }
+
+    fn one() -> i128 {
+        return 1;
+    }
+
+    fn min() -> i128 {
+        return i128::MIN;
+    }
+
+    fn max() -> i128 {
+        return i128::MAX;
+    }
+
+    fn byte_max() -> i128 {
+        return 255;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> i128 {
+        return value as i128;
+    }
+
+    fn wrapping_add(self, other: i128) -> i128 {
+        return i128::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: i128) -> i128 {
+        return i128::wrapping_sub(self, other);
+    }
+
+    fn unsigned_rem(self, other: i128) -> i128 {
+        return (self as u128 % other as u128) as i128;
+    }
+
+    fn unsigned_to_f64(self) -> f64 {
+        return self as u128 as f64;
+    }
+}
+
+impl ValidRandomNumber for isize {
+    fn zero() -> isize {
+        return 0;
+    }
+
+    fn one() -> isize {
+        return 1;
+    }
+
+    fn min() -> isize {
+        return isize::MIN;
+    }
+
+    fn max() -> isize {
+        return isize::MAX;
+    }
+
+    fn byte_max() -> isize {
+        return 255;
+    }
+
+    fn to_u8(self) -> u8 {
+        return self as u8;
+    }
+
+    fn to_usize(self) -> usize {
+        return self as usize;
+    }
+
+    fn to_u64(self) -> u64 {
+        return self as u64;
+    }
+
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+
+    fn from_usize(value: usize) -> isize {
+        return value as isize;
+    }
+
+    fn wrapping_add(self, other: isize) -> isize {
+        return isize::wrapping_add(self, other);
+    }
+
+    fn wrapping_sub(self, other: isize) -> isize {
+        return isize::wrapping_sub(self, other);
+    }
+
+    fn unsigned_rem(self, other: isize) -> isize {
+        return (self as usize % other as usize) as isize;
+    }
+
+    fn unsigned_to_f64(self) -> f64 {
+        return self as usize as f64;
+    }
+}
+
+/// The `ValidRandomRange` trait.
+/// 
+/// It defines what makes a struct a valid range to use with a type that implements
+/// `ValidRandomNumber`.
+/// 
+/// Currently implemented for all `Range` structs, and for `(start, end)` tuples, which work like a
+/// `start..end` range.
+pub trait ValidRandomRange<T: ValidRandomNumber> {
+    /// Returns the starting value of the range.
+    fn _start(&self) -> T;
+
+    /// Returns the ending value of the range.
+    fn _end(&self) -> T;
+
+    /// Returns whether the value returned by `_end` is part of the range.
+    fn _inclusive(&self) -> bool {
+        return false;
+    }
+}
+
+impl<T: ValidRandomNumber> ValidRandomRange<T> for Range<T> {
+    fn _start(&self) -> T {
+        return self.start;
+    }
+
+    fn _end(&self) -> T {
+        return self.end;
+    }
+}
+
+impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeInclusive<T> {
+    fn _start(&self) -> T {
+        return *self.start();
+    }
+
+    fn _end(&self) -> T {
+        return *self.end();
+    }
+
+    fn _inclusive(&self) -> bool {
+        return true;
+    }
+}
+
+impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeFrom<T> {
+    fn _start(&self) -> T {
+        return self.start;
+    }
+
+    fn _end(&self) -> T {
+        return T::max();
+    }
+
+    fn _inclusive(&self) -> bool {
+        return true;
+    }
+}
+
+impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeTo<T> {
+    fn _start(&self) -> T {
+        return T::min();
+    }
+
+    fn _end(&self) -> T {
+        return self.end;
+    }
+}
+
+impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeToInclusive<T> {
+    fn _start(&self) -> T {
+        return T::min();
+    }
+
+    fn _end(&self) -> T {
+        return self.end;
+    }
+
+    fn _inclusive(&self) -> bool {
+        return true;
+    }
+}
+
+impl<T: ValidRandomNumber> ValidRandomRange<T> for RangeFull {
+    fn _start(&self) -> T {
+        return T::min();
+    }
+
+    fn _end(&self) -> T {
+        return T::max();
+    }
+
+    fn _inclusive(&self) -> bool {
+        return true;
+    }
+}
+
+impl<T: ValidRandomNumber> ValidRandomRange<T> for (T, T) {
+    fn _start(&self) -> T {
+        return self.0;
+    }
+
+    fn _end(&self) -> T {
+        return self.1;
+    }
+}
+
+/// The `ValidRandomSeed` trait.
+/// 
+/// It defines what makes a type a valid seed for a random algorithm.
+/// 
+/// Currently implemented for:
+/// * u32
+/// * u64
+/// * u128
+/// * [u64; 2]
+/// * [u8; 32]
+/// * a pair of any of them
+pub trait ValidRandomSeed: Copy + Debug + ValidRandomState {
+    /// Creates a seed from a `u64` value, mixing it through SplitMix64 so that similar values give
+    /// unrelated seeds.
+    fn from_u64(value: u64) -> Self;
+}
+
+impl ValidRandomSeed for u32 {
+    fn from_u64(value: u64) -> u32 {
+        return (SplitMix64::new(value).next() >> 32) as u32;
+    }
+}
+
+impl ValidRandomSeed for u64 {
+    fn from_u64(value: u64) -> u64 {
+        return SplitMix64::new(value).next();
+    }
+}
+
+impl ValidRandomSeed for u128 {
+    fn from_u64(value: u64) -> u128 {
+        let mut splitmix = SplitMix64::new(value);
+        let high = u128::from(splitmix.next());
+
+        return (high << 64) | u128::from(splitmix.next());
+    }
+}
+
+impl ValidRandomSeed for [u64; 2] {
+    fn from_u64(value: u64) -> [u64; 2] {
+        let mut splitmix = SplitMix64::new(value);
+        return [splitmix.next(), splitmix.next()];
+    }
+}
+
+impl ValidRandomSeed for [u8; 32] {
+    fn from_u64(value: u64) -> [u8; 32] {
+        let mut splitmix = SplitMix64::new(value);
+        let mut seed = [0; 32];
+
+        for chunk in seed.chunks_exact_mut(8) {
+            chunk.copy_from_slice(&splitmix.next().to_le_bytes());
+        }
+
+        return seed;
+    }
+}
+
+impl<S: ValidRandomSeed> ValidRandomSeed for (S, S) {
+    fn from_u64(value: u64) -> (S, S) {
+        let mut splitmix = SplitMix64::new(value);
+        return (S::from_u64(splitmix.next()), S::from_u64(splitmix.next()));
+    }
+}
+
+/// The `ValidRandomState` trait.
+/// 
+/// It defines how the internal state of an algorithm is turned into bytes and back, so that it can
+/// be stored in a compact way.
+/// 
+/// Currently implemented for:
+/// * u8
+/// * u32
+/// * u64
+/// * u128
+/// * usize, stored as a u64
+/// * f64, stored as its bits
+/// * arrays of any of them
+/// * pairs of any of them
+/// * options of any of them, stored with a leading byte telling if there's a value
+pub trait ValidRandomState {
+    /// Appends the little endian bytes of the state to `bytes`.
+    fn write_bytes(&self, bytes: &mut Vec<u8>);
+
+    /// Reads a state from the start of `bytes`, returning it along with the bytes left, or `None`
+    /// if there aren't enough bytes.
+    fn read_bytes(bytes: &[u8]) -> Option<(Self, &[u8])> where Self: Sized;
+}
+
+impl ValidRandomState for u8 {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.push(*self);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(u8, &[u8])> {
+        let (value, rest) = bytes.split_first()?;
+        return Some((*value, rest));
+    }
+}
+
+impl ValidRandomState for u32 {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(u32, &[u8])> {
+        let (value, rest) = bytes.split_first_chunk::<4>()?;
+        return Some((u32::from_le_bytes(*value), rest));
+    }
+}
+
+impl ValidRandomState for u64 {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(u64, &[u8])> {
+        let (value, rest) = bytes.split_first_chunk::<8>()?;
+        return Some((u64::from_le_bytes(*value), rest));
+    }
+}
+
+impl ValidRandomState for u128 {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(u128, &[u8])> {
+        let (value, rest) = bytes.split_first_chunk::<16>()?;
+        return Some((u128::from_le_bytes(*value), rest));
+    }
+}
+
+// Stored as a u64 so the bytes are the same on every platform.
+impl ValidRandomState for usize {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        (*self as u64).write_bytes(bytes);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(usize, &[u8])> {
+        let (value, rest) = u64::read_bytes(bytes)?;
+        return Some((usize::try_from(value).ok()?, rest));
+    }
+}
+
+impl ValidRandomState for f64 {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        self.to_bits().write_bytes(bytes);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(f64, &[u8])> {
+        let (value, rest) = u64::read_bytes(bytes)?;
+        return Some((f64::from_bits(value), rest));
+    }
+}
+
+impl<S: ValidRandomState> ValidRandomState for Option<S> {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Some(value) => {
+                bytes.push(1);
+                value.write_bytes(bytes);
+            },
+            None => bytes.push(0)
+        }
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(Option<S>, &[u8])> {
+        let (flag, rest) = bytes.split_first()?;
+
+        return match flag {
+            0 => Some((None, rest)),
+            1 => S::read_bytes(rest).map(|(value, rest)| (Some(value), rest)),
+            _ => None
+        };
+    }
+}
+
+impl<S: ValidRandomState, const N: usize> ValidRandomState for [S; N] {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        for value in self {
+            value.write_bytes(bytes);
+        }
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<([S; N], &[u8])> {
+        let mut values: Vec<S> = Vec::with_capacity(N);
+        let mut rest = bytes;
+
+        for _ in 0..N {
+            let (value, left) = S::read_bytes(rest)?;
+            values.push(value);
+            rest = left;
+        }
+
+        return Some((values.try_into().ok()?, rest));
+    }
+}
+
+impl<A: ValidRandomState, B: ValidRandomState> ValidRandomState for (A, B) {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        self.0.write_bytes(bytes);
+        self.1.write_bytes(bytes);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<((A, B), &[u8])> {
+        let (first, rest) = A::read_bytes(bytes)?;
+        let (second, rest) = B::read_bytes(rest)?;
+        return Some(((first, second), rest));
+    }
+}
+
+/// The `Shuffleable` trait.
+/// 
+/// It defines what makes a collection able to have its contents shuffled.
+/// 
+/// Currently implemented for:
+/// * Vec
+/// * slices
+/// * VecDeque
+pub trait Shuffleable {
+    /// Returns the amount of elements in the collection.
+    fn len(&self) -> usize;
+
+    /// Returns whether the collection has no elements.
+    fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Swaps the elements at positions `i` and `j`.
+    fn swap(&mut self, i: usize, j: usize);
+}
+
+impl<G> Shuffleable for Vec<G> {
+    fn len(&self) -> usize {
+        return Vec::len(self);
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j);
+    }
+}
+
+impl<G> Shuffleable for [G] {
+    fn len(&self) -> usize {
+        return <[G]>::len(self);
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        <[G]>::swap(self, i, j);
+    }
+}
+
+impl<G> Shuffleable for VecDeque<G> {
+    fn len(&self) -> usize {
+        return VecDeque::len(self);
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        VecDeque::swap(self, i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::marker::PhantomData;
+    use crate::algorithm::RandomAlgorithm;
+    use crate::mersennetwister::MersenneTwister;
+
+    // Algorithm that narrows 64 bits numbers, made of two numbers generated by the Mersenne
+    // Twister, to the given type.
+    struct Narrow<N> {
+        algorithm: MersenneTwister,
+        number: PhantomData<N>
+    }
+
+    impl<N: ValidRandomNumber> RandomAlgorithm for Narrow<N> {
+        type Seed = u32;
+        type Number = N;
+
+        fn new(seed: Self::Seed) -> Result<Narrow<N>, &'static str> {
+            let algorithm = MersenneTwister::new(seed)?;
+            return Ok(Narrow { algorithm, number: PhantomData });
+        }
+
+        fn default() -> Narrow<N> {
+            return Narrow { algorithm: MersenneTwister::default(), number: PhantomData };
+        }
+
+        fn next_raw(&mut self) -> N {
+            let high = self.algorithm.next_raw().to_u64() << 32;
+            return N::from_usize((high | self.algorithm.next_raw().to_u64()) as usize);
+        }
+    }
+
+    #[test]
+    fn unsigned_min() {
+        assert_eq!(<u8 as ValidRandomNumber>::min(), 0);
+        assert_eq!(<u16 as ValidRandomNumber>::min(), 0);
+        assert_eq!(<u32 as ValidRandomNumber>::min(), 0);
+        assert_eq!(<u64 as ValidRandomNumber>::min(), 0);
+        assert_eq!(<u128 as ValidRandomNumber>::min(), 0);
+        assert_eq!(<usize as ValidRandomNumber>::min(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn signed_randrange() {
+        let mut random: Narrow<i32> = Narrow::new(10).unwrap();
+        let values: Vec<i32> = (0..1000).map(|_| random.randrange(i32::MIN..=i32::MAX)).collect();
+        assert!(values.iter().any(|&value| value < 0) && values.iter().any(|&value| value > 0));
+
+        let values: Vec<i32> = (0..1000).map(|_| random.randrange(-5..=-1)).collect();
+        assert!(values.iter().all(|value| (-5..=-1).contains(value)));
+        assert!(values.contains(&-5) && values.contains(&-1));
+        assert!((0..100).all(|_| (-3..4).contains(&random.randrange(3..-3))));
+
+        let mut random: Narrow<i64> = Narrow::new(10).unwrap();
+        let values: Vec<i64> = (0..1000).map(|_| random.randrange(i64::MIN..=i64::MAX)).collect();
+        assert!(values.iter().any(|&value| value < 0) && values.iter().any(|&value| value > 0));
+
+        let values: Vec<i64> = (0..100).map(|_| random.randrange(i64::MAX - 2..=i64::MAX))
+            .collect();
+        assert!(values.contains(&(i64::MAX - 2)) && values.contains(&i64::MAX));
+        let values: Vec<i64> = (0..100).map(|_| random.randrange(i64::MIN..=i64::MIN + 2))
+            .collect();
+        assert!(values.contains(&i64::MIN) && values.contains(&(i64::MIN + 2)));
+        assert_eq!((-3i64).checked_to_u8(), None);
+    }
+
+    #[test]
+    fn seed_from_u64() {
+        assert_ne!(u32::from_u64(1), u32::from_u64(2));
+        assert_eq!(u64::from_u64(1234567), 6457827717110365317);
+        assert_eq!(<[u64; 2]>::from_u64(1234567), [6457827717110365317, 3203168211198807973]);
+    }
+
+    #[test]
+    fn state_bytes() {
+        let state: ([u32; 2], usize) = ([1, 0x01020304], 7);
+        let mut bytes: Vec<u8> = Vec::new();
+        state.write_bytes(&mut bytes);
+        assert_eq!(bytes, vec![1, 0, 0, 0, 4, 3, 2, 1, 7, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(<([u32; 2], usize)>::read_bytes(&bytes), Some((state, &[][..])));
+        assert_eq!(<([u32; 2], usize)>::read_bytes(&bytes[1..]), None);
+
+        let spare: Option<f64> = Some(0.5);
+        let mut bytes: Vec<u8> = Vec::new();
+        spare.write_bytes(&mut bytes);
+        None::<f64>.write_bytes(&mut bytes);
+        assert_eq!(bytes, vec![1, 0, 0, 0, 0, 0, 0, 0xE0, 0x3F, 0]);
+        let decoded = <(Option<f64>, Option<f64>)>::read_bytes(&bytes);
+        assert_eq!(decoded, Some(((spare, None), &[][..])));
+        assert_eq!(Option::<f64>::read_bytes(&[2]), None);
+    }
+
+    #[test]
+    fn checked_to_u8() {
+        assert_eq!(200u32.checked_to_u8(), Some(200));
+        assert_eq!(255u64.checked_to_u8(), Some(255));
+        assert_eq!(256u16.checked_to_u8(), None);
+        assert_eq!(u128::MAX.checked_to_u8(), None);
+        assert_eq!(u8::MAX.checked_to_u8(), Some(255));
+    }
+
+    #[test]
+    fn checked_width() {
+        assert_eq!(u32::checked_width(3, 10), Some(7));
+        assert_eq!(u32::checked_width(0, u32::MAX), Some(u32::MAX));
+        assert_eq!(u32::checked_width(10, 3), None);
+    }
+
+    #[test]
+    fn usize_randrange() {
+        let mut random: Narrow<usize> = Narrow::new(10).unwrap();
+        assert_eq!(<usize as ValidRandomNumber>::bits(), usize::BITS);
+
+        for _ in 0..1000 {
+            let value = random.randrange(0usize..100);
+            assert!(value < 100);
+        }
+    }
+
+    #[test]
+    fn tuple_randrange() {
+        let mut random = MersenneTwister::new(10).unwrap();
+
+        for _ in 0..1000 {
+            let value = random.randrange((2u32, 8u32));
+            assert!((2..8).contains(&value));
+        }
+    }
+
+    #[test]
+    fn u8_randrange() {
+        let mut random: Narrow<u8> = Narrow::new(10).unwrap();
+
+        for _ in 0..1000 {
+            let value = random.randrange(0u8..10u8);
+            assert!(value < 10);
+        }
+    }
+
+    #[test]
+    fn u8_randrange_overflow() {
+        let mut random: Narrow<u8> = Narrow::new(10).unwrap();
+
+        for _ in 0..1000 {
+            assert!(random.randrange(250u8..=255u8) >= 250);
+            assert!(random.randrange(1u8..=u8::MAX) >= 1);
+            random.randrange(0u8..=u8::MAX);
+            random.randrange(..=u8::MAX);
+            random.randrange(..);
+        }
+    }
+
+    #[test]
+    fn u16_randrange() {
+        let mut random: Narrow<u16> = Narrow::new(10).unwrap();
+
+        for _ in 0..1000 {
+            let value = random.randrange(1000u16..2000u16);
+            assert!((1000..2000).contains(&value));
+        }
     }
 }
@@ -0,0 +1,124 @@
+use crate::algorithm::RandomAlgorithm;
+use crate::rand::Random;
+
+// Vose's alias method as described in
+// https://www.keithschwarz.com/darts-dice-coins/
+/// Struct `WeightedSampler`, used to draw random indices according to a fixed set of weights in
+/// constant time.
+/// 
+/// e.g.
+/// ```rust
+/// # use rnglib::{Random, MersenneTwister, weighted_sampler};
+/// let mut rng: Random<MersenneTwister> = Random::new();
+/// let sampler = weighted_sampler(&[1.0, 2.0, 7.0]).unwrap();
+/// let index: usize = sampler.sample(&mut rng);
+/// ```
+pub struct WeightedSampler {
+    probabilities: Vec<f64>,
+    aliases: Vec<usize>
+}
+
+/// Returns a `Result` containing a `WeightedSampler` for the given weights.
+/// 
+/// The weights don't need to add up to 1, but they can't be negative and at least one of them
+/// must be positive.
+/// 
+/// e.g.
+/// ```rust
+/// # use rnglib::{WeightedSampler, weighted_sampler};
+/// let sampler: Result<WeightedSampler, &'static str> = weighted_sampler(&[1.0, 2.0, 7.0]);
+/// ```
+pub fn weighted_sampler(weights: &[f64]) -> Result<WeightedSampler, &'static str> {
+    if weights.iter().any(|weight| !weight.is_finite() || *weight < 0.0) {
+        return Err("weights must be finite and can't be negative");
+    }
+
+    let total: f64 = weights.iter().sum();
+
+    if total <= 0.0 {
+        return Err("at least one weight must be positive");
+    }
+
+    let length = weights.len();
+    let mut scaled: Vec<f64> = weights.iter()
+        .map(|weight| weight * length as f64 / total)
+        .collect();
+    let mut probabilities: Vec<f64> = vec![1.0; length];
+    let mut aliases: Vec<usize> = (0..length).collect();
+    let mut small: Vec<usize> = Vec::new();
+    let mut large: Vec<usize> = Vec::new();
+
+    for (index, probability) in scaled.iter().enumerate() {
+        if *probability < 1.0 {
+            small.push(index);
+        } else {
+            large.push(index);
+        }
+    }
+
+    while let (Some(less), Some(more)) = (small.pop(), large.pop()) {
+        probabilities[less] = scaled[less];
+        aliases[less] = more;
+        scaled[more] = scaled[more] + scaled[less] - 1.0;
+
+        if scaled[more] < 1.0 {
+            small.push(more);
+        } else {
+            large.push(more);
+        }
+    }
+
+    // whatever is left has a probability of 1, give or take rounding errors
+    return Ok(WeightedSampler { probabilities, aliases });
+}
+
+impl WeightedSampler {
+    /// Returns a random index chosen according to the weights of the sampler.
+    /// 
+    /// e.g.
+    /// ```rust
+    /// # use rnglib::{Random, MersenneTwister, weighted_sampler};
+    /// let mut rng: Random<MersenneTwister> = Random::new();
+    /// let sampler = weighted_sampler(&[1.0, 2.0, 7.0]).unwrap();
+    /// let index: usize = sampler.sample(&mut rng);
+    /// ```
+    pub fn sample<T: RandomAlgorithm>(&self, rng: &mut Random<T>) -> usize {
+        let index = rng.index(self.probabilities.len()).unwrap();
+
+        if rng.random() < self.probabilities[index] {
+            return index;
+        }
+
+        return self.aliases[index];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mersennetwister::MersenneTwister;
+
+    #[test]
+    fn weighted_sampler_distribution() {
+        let mut rng: Random<MersenneTwister> = Random::seed(10).unwrap();
+        let weights = [1.0, 2.0, 0.0, 7.0];
+        let sampler = weighted_sampler(&weights).unwrap();
+        let mut counts = [0; 4];
+
+        for _ in 0..10000 {
+            counts[sampler.sample(&mut rng)] += 1;
+        }
+
+        assert!((900..1100).contains(&counts[0]));
+        assert!((1850..2150).contains(&counts[1]));
+        assert_eq!(counts[2], 0);
+        assert!((6800..7200).contains(&counts[3]));
+    }
+
+    #[test]
+    fn weighted_sampler_invalid() {
+        assert!(weighted_sampler(&[]).is_err());
+        assert!(weighted_sampler(&[0.0, 0.0]).is_err());
+        assert!(weighted_sampler(&[1.0, -1.0]).is_err());
+    }
+}
@@ -1,11 +1,9 @@
-use std::time::{SystemTime, UNIX_EPOCH};
-
-use crate::values::ValidRandomRange;
-use crate::algorithm::RandomAlgorithm;
+use crate::algorithm::{RandomAlgorithm, StatefulAlgorithm, time_seed};
 
 // Implementation for linear xor shift algorithms
 // https://en.wikipedia.org/wiki/Xorshift#Example_implementation
 /// Linear 32 bits xor shift algorithm.
+#[derive(Clone, Debug, PartialEq)]
 pub struct XORShift32 {
     state: u32
 }
@@ -13,36 +11,58 @@ pub struct XORShift32 {
 impl RandomAlgorithm for XORShift32 {
     type Seed = u32;
     type Number = u32;
+    const NAME: &'static str = "XORShift32";
+    const PERIOD_LOG2: u32 = 32;
 
     fn new(seed: Self::Seed) -> Result<XORShift32, &'static str> {
-        if seed == 0 {
+        XORShift32::validate_seed(&seed)?;
+        return Ok(XORShift32 { state: seed });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
+        if *seed == 0 {
             return Err("seed must be initialized to non-zero");
         }
 
-        return Ok(XORShift32 { state: seed });
+        return Ok(());
     }
 
     fn default() -> XORShift32 {
-        let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
-            Ok(duration) => duration.subsec_micros(),
-            Err(_) => 1
+        return match XORShift32::from_u64(time_seed()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => XORShift32 { state: 1 }
         };
-
-        return XORShift32 { state: seed };
     }
 
-    fn randrange<R: ValidRandomRange<Self::Number>>(&mut self, range: R) -> Self::Number {
+    fn next_raw(&mut self) -> Self::Number {
         let mut x = self.state;
         x ^= x << 13;
         x ^= x >> 17;
         x ^= x << 5;
         self.state = x;
 
-        return x % (range._end() - range._start()) + range._start();
+        return x;
+    }
+}
+
+impl StatefulAlgorithm for XORShift32 {
+    type State = u32;
+
+    fn state(&self) -> Self::State {
+        return self.state;
+    }
+
+    fn from_state(state: Self::State) -> XORShift32 {
+        return XORShift32 { state };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        return XORShift32::validate_seed(state);
     }
 }
 
 /// Linear 64 bits xor shift algorithm.
+#[derive(Clone, Debug, PartialEq)]
 pub struct XORShift64 {
     state: u64
 }
@@ -50,36 +70,58 @@ pub struct XORShift64 {
 impl RandomAlgorithm for XORShift64 {
     type Seed = u64;
     type Number = u64;
+    const NAME: &'static str = "XORShift64";
+    const PERIOD_LOG2: u32 = 64;
 
     fn new(seed: Self::Seed) -> Result<XORShift64, &'static str> {
-        if seed == 0 {
+        XORShift64::validate_seed(&seed)?;
+        return Ok(XORShift64 { state: seed });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
+        if *seed == 0 {
             return Err("seed must be initialized to non-zero");
         }
 
-        return Ok(XORShift64 { state: seed });
+        return Ok(());
     }
 
     fn default() -> XORShift64 {
-        let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
-            Ok(duration) => duration.as_secs(),
-            Err(_) => 1
+        return match XORShift64::from_u64(time_seed()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => XORShift64 { state: 1 }
         };
-
-        return XORShift64 { state: seed };
     }
 
-    fn randrange<R: ValidRandomRange<Self::Number>>(&mut self, range: R) -> Self::Number {
+    fn next_raw(&mut self) -> Self::Number {
         let mut x = self.state;
         x ^= x << 13;
         x ^= x >> 7;
         x ^= x << 17;
         self.state = x;
 
-        return x % (range._end() - range._start()) + range._start();
+        return x;
+    }
+}
+
+impl StatefulAlgorithm for XORShift64 {
+    type State = u64;
+
+    fn state(&self) -> Self::State {
+        return self.state;
+    }
+
+    fn from_state(state: Self::State) -> XORShift64 {
+        return XORShift64 { state };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        return XORShift64::validate_seed(state);
     }
 }
 
 /// Linear 128 bits xor shift algorithm.
+#[derive(Clone, Debug, PartialEq)]
 pub struct XORShift128 {
     state: u128
 }
@@ -87,38 +129,61 @@ pub struct XORShift128 {
 impl RandomAlgorithm for XORShift128 {
     type Seed = u128;
     type Number = u128;
+    const NAME: &'static str = "XORShift128";
+    // the shifts come from the 32 bits words variant, their period on a single u128 is unknown
+    const PERIOD_LOG2: u32 = 0;
 
     fn new(seed: Self::Seed) -> Result<XORShift128, &'static str> {
-        if seed == 0 {
+        XORShift128::validate_seed(&seed)?;
+        return Ok(XORShift128 { state: seed });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
+        if *seed == 0 {
             return Err("seed must be initialized to non-zero");
         }
 
-        return Ok(XORShift128 { state: seed });
+        return Ok(());
     }
 
     fn default() -> XORShift128 {
-        let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
-            Ok(duration) => duration.as_micros(),
-            Err(_) => 1
+        return match XORShift128::from_u64(time_seed()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => XORShift128 { state: 1 }
         };
-
-        return XORShift128 { state: seed };
     }
 
-    fn randrange<R: ValidRandomRange<Self::Number>>(&mut self, range: R) -> Self::Number {
+    fn next_raw(&mut self) -> Self::Number {
         let mut x = self.state;
         x ^= x << 11;
         x ^= x >> 8;
         x ^= x << 19;
         self.state = x;
 
-        return x % (range._end() - range._start()) + range._start();
+        return x;
+    }
+}
+
+impl StatefulAlgorithm for XORShift128 {
+    type State = u128;
+
+    fn state(&self) -> Self::State {
+        return self.state;
+    }
+
+    fn from_state(state: Self::State) -> XORShift128 {
+        return XORShift128 { state };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        return XORShift128::validate_seed(state);
     }
 }
 
 // Implementation for xor shift + algorithm
 // https://en.wikipedia.org/wiki/Xorshift#xorshift+
 /// 128 bits xor shift+ algorithm.
+#[derive(Clone, Debug, PartialEq)]
 pub struct XORShift128Plus {
     state: [u64; 2]
 }
@@ -126,25 +191,31 @@ pub struct XORShift128Plus {
 impl RandomAlgorithm for XORShift128Plus {
     type Seed = [u64; 2];
     type Number = u128;
+    const NAME: &'static str = "XORShift128Plus";
+    // the first word of the state never changes, so the period is at most 2^64 and isn't known
+    const PERIOD_LOG2: u32 = 0;
 
     fn new(seed: Self::Seed) -> Result<XORShift128Plus, &'static str> {
+        XORShift128Plus::validate_seed(&seed)?;
+        return Ok(XORShift128Plus { state: seed });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
         if seed[0] | seed[1] == 0 {
             return Err("at least one bit of the seed must be initialized to non-zero");
         }
 
-        return Ok(XORShift128Plus { state: seed });
+        return Ok(());
     }
 
     fn default() -> XORShift128Plus {
-        let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
-            Ok(duration) => duration.as_secs(),
-            Err(_) => 1
+        return match XORShift128Plus::from_u64(time_seed()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => XORShift128Plus { state: [1, 1] }
         };
-
-        return XORShift128Plus { state: [seed, seed + 1] };
     }
 
-    fn randrange<R: ValidRandomRange<Self::Number>>(&mut self, range: R) -> Self::Number {
+    fn next_raw(&mut self) -> Self::Number {
         let mut x = self.state[0];
         let y = self.state[1];
         x ^= x << 23;
@@ -152,7 +223,88 @@ impl RandomAlgorithm for XORShift128Plus {
         x ^= y ^ (y >> 5);
         self.state[1] = x;
 
-        return u128::from(x + y) % (range._end() - range._start()) + range._start();
+        return u128::from(x.wrapping_add(y));
+    }
+
+    fn raw_bits() -> u32 {
+        return 64;
+    }
+}
+
+impl StatefulAlgorithm for XORShift128Plus {
+    type State = [u64; 2];
+
+    fn state(&self) -> Self::State {
+        return self.state;
+    }
+
+    fn from_state(state: Self::State) -> XORShift128Plus {
+        return XORShift128Plus { state };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        return XORShift128Plus::validate_seed(state);
+    }
+}
+
+// Implementation for xor shift * algorithm
+// https://en.wikipedia.org/wiki/Xorshift#xorshift*
+/// 64 bits xor shift* algorithm, which scrambles the output with a multiplication.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XORShift64Star {
+    state: u64
+}
+
+impl RandomAlgorithm for XORShift64Star {
+    type Seed = u64;
+    type Number = u64;
+    const NAME: &'static str = "XORShift64Star";
+    const PERIOD_LOG2: u32 = 64;
+
+    fn new(seed: Self::Seed) -> Result<XORShift64Star, &'static str> {
+        XORShift64Star::validate_seed(&seed)?;
+        return Ok(XORShift64Star { state: seed });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
+        if *seed == 0 {
+            return Err("seed must be initialized to non-zero");
+        }
+
+        return Ok(());
+    }
+
+    fn default() -> XORShift64Star {
+        return match XORShift64Star::from_u64(time_seed()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => XORShift64Star { state: 1 }
+        };
+    }
+
+    fn next_raw(&mut self) -> Self::Number {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+
+        return x.wrapping_mul(0x2545F4914F6CDD1D);
+    }
+}
+
+impl StatefulAlgorithm for XORShift64Star {
+    type State = u64;
+
+    fn state(&self) -> Self::State {
+        return self.state;
+    }
+
+    fn from_state(state: Self::State) -> XORShift64Star {
+        return XORShift64Star { state };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        return XORShift64Star::validate_seed(state);
     }
 }
 
@@ -167,6 +319,65 @@ mod tests {
         assert_eq!(value, 3);
     }
 
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn xorshift32_reversed_range() {
+        let mut random = XORShift32::new(10).unwrap();
+        let mut reversed = XORShift32::new(10).unwrap();
+
+        for _ in 0..100 {
+            let value = reversed.randrange(10..3);
+            assert!((3..10).contains(&value));
+            assert_eq!(value, random.randrange(3..10));
+            assert_eq!(reversed.randrange(7..=2), random.randrange(2..=7));
+        }
+    }
+
+    #[test]
+    fn xorshift32_from_u64() {
+        let mut random = XORShift32::from_u64(10).unwrap();
+        let mut other = XORShift32::from_u64(10 + (1 << 32)).unwrap();
+        assert_ne!(random.next_raw(), other.next_raw());
+    }
+
+    #[test]
+    fn xorshift_default_differs() {
+        assert_ne!(XORShift32::default().next_raw(), XORShift32::default().next_raw());
+        assert_ne!(XORShift64::default().next_raw(), XORShift64::default().next_raw());
+        assert_ne!(XORShift128::default().next_raw(), XORShift128::default().next_raw());
+        assert_ne!(XORShift128Plus::default().next_raw(), XORShift128Plus::default().next_raw());
+    }
+
+    #[test]
+    fn xorshift_validate_seed() {
+        assert!(XORShift32::validate_seed(&0).is_err());
+        assert!(XORShift64::validate_seed(&0).is_err());
+        assert!(XORShift128::validate_seed(&0).is_err());
+        assert!(XORShift128Plus::validate_seed(&[0, 0]).is_err());
+        assert!(XORShift32::validate_seed(&10).is_ok());
+        assert!(XORShift128Plus::validate_seed(&[0, 1]).is_ok());
+        assert_eq!(XORShift64::validate_seed(&0).err(), XORShift64::new(0).err());
+    }
+
+    #[test]
+    fn xorshift_period() {
+        assert_eq!(XORShift32::PERIOD_LOG2, 32);
+        assert_eq!(XORShift64::PERIOD_LOG2, 64);
+        assert_eq!(XORShift128::PERIOD_LOG2, 0);
+        assert_eq!(XORShift128Plus::PERIOD_LOG2, 0);
+    }
+
+    #[test]
+    fn xorshift_equal_states() {
+        let mut random = XORShift64::new(10).unwrap();
+        let mut same = XORShift64::new(10).unwrap();
+        random.next_raw();
+        same.next_raw();
+        assert_eq!(random, same);
+        random.next_raw();
+        assert_ne!(random, same);
+    }
+
     #[test]
     fn xorshift64_random_value() {
         let mut random = XORShift64::new(10).unwrap();
@@ -187,4 +398,35 @@ mod tests {
         let value = random.randrange(1..5);
         assert_eq!(value, 3);
     }
+
+    #[test]
+    fn xorshift_from_u64() {
+        let mut random = XORShift64::from_u64(10).unwrap();
+        assert_ne!(random.next_raw(), XORShift64::new(10).unwrap().next_raw());
+        assert!(XORShift128::from_u64(0).is_ok());
+        assert!(XORShift128Plus::from_u64(0).is_ok());
+    }
+
+    #[test]
+    fn xorshift128plus_restore_state() {
+        let mut random = XORShift128Plus::new([10, 20]).unwrap();
+        random.next_raw();
+        let mut restored = XORShift128Plus::from_state(random.state());
+        assert_eq!(restored.next_raw(), random.next_raw());
+    }
+
+    #[test]
+    fn xorshift64star_sequence() {
+        let mut random = XORShift64Star::new(1).unwrap();
+        let values: Vec<u64> = (0..3).map(|_| random.next_raw()).collect();
+        assert_eq!(values, vec![5180492295206395165, 12380297144915551517, 13389498078930870103]);
+        assert!(XORShift64Star::new(0).is_err());
+    }
+
+    #[test]
+    fn xorshift128plus_large_state() {
+        let mut random = XORShift128Plus::new([u64::MAX, u64::MAX]).unwrap();
+        let value = random.randrange(1..5);
+        assert!((1..5).contains(&value));
+    }
 }
@@ -0,0 +1,101 @@
+use crate::algorithm::{RandomAlgorithm, StatefulAlgorithm, time_seed};
+use crate::splitmix::SplitMix32;
+
+// Implementation for the xoshiro128++ algorithm
+// https://prng.di.unimi.it/xoshiro128plusplus.c
+/// 128 bits xoshiro++ algorithm, working only with 32 bits numbers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Xoshiro128PlusPlus {
+    state: [u32; 4]
+}
+
+impl Xoshiro128PlusPlus {
+    /// Expands a seed into a whole state.
+    fn expand(seed: u32) -> [u32; 4] {
+        let mut splitmix = SplitMix32::new(seed);
+        return [splitmix.next(), splitmix.next(), splitmix.next(), splitmix.next()];
+    }
+}
+
+impl RandomAlgorithm for Xoshiro128PlusPlus {
+    type Seed = u32;
+    type Number = u32;
+    const NAME: &'static str = "Xoshiro128PlusPlus";
+    const PERIOD_LOG2: u32 = 128;
+
+    fn new(seed: Self::Seed) -> Result<Xoshiro128PlusPlus, &'static str> {
+        Xoshiro128PlusPlus::validate_seed(&seed)?;
+        return Ok(Xoshiro128PlusPlus { state: Xoshiro128PlusPlus::expand(seed) });
+    }
+
+    fn validate_seed(seed: &Self::Seed) -> Result<(), &'static str> {
+        if Xoshiro128PlusPlus::expand(*seed) == [0; 4] {
+            return Err("at least one bit of the state must be initialized to non-zero");
+        }
+
+        return Ok(());
+    }
+
+    fn default() -> Xoshiro128PlusPlus {
+        return match Xoshiro128PlusPlus::from_u64(time_seed()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => Xoshiro128PlusPlus { state: [1, 2, 3, 4] }
+        };
+    }
+
+    fn next_raw(&mut self) -> Self::Number {
+        let result = self.state[0].wrapping_add(self.state[3]).rotate_left(7)
+            .wrapping_add(self.state[0]);
+        let t = self.state[1] << 9;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(11);
+
+        return result;
+    }
+}
+
+impl StatefulAlgorithm for Xoshiro128PlusPlus {
+    type State = [u32; 4];
+
+    fn state(&self) -> Self::State {
+        return self.state;
+    }
+
+    fn from_state(state: Self::State) -> Xoshiro128PlusPlus {
+        return Xoshiro128PlusPlus { state };
+    }
+
+    fn validate_state(state: &Self::State) -> Result<(), &'static str> {
+        if *state == [0; 4] {
+            return Err("at least one bit of the state must be initialized to non-zero");
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro128plusplus_reference_values() {
+        let mut random = Xoshiro128PlusPlus::from_state([1, 2, 3, 4]);
+        let values: Vec<u32> = (0..4).map(|_| random.next_raw()).collect();
+        assert_eq!(values, vec![641, 1573767, 3222811527, 3517856514]);
+    }
+
+    #[test]
+    fn xoshiro128plusplus_random_value() {
+        let mut random = Xoshiro128PlusPlus::new(10).unwrap();
+        let mut same = Xoshiro128PlusPlus::new(10).unwrap();
+        let value = random.randrange(1..5);
+        assert!((1..5).contains(&value));
+        assert_eq!(value, same.randrange(1..5));
+    }
+}